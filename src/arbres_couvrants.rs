@@ -0,0 +1,188 @@
+// Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
+#![allow(unused_parens)]
+
+// Arbres couvrants de poids minimal (ACM) d'un graphe pondéré non orienté
+// https://fr.wikipedia.org/wiki/Arbre_couvrant_de_poids_minimal
+// Si le graphe n'est pas connexe, on obtient une forêt couvrante de poids minimal
+// (un arbre par composante connexe).
+// Les arêtes sont considérées comme non orientées, même si le graphe est orienté.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::graphe_pondere::GraphePondere;
+use crate::graphes::{ElementFile, Sommets, Voisins};
+
+// Ensembles disjoints (union-find), avec compression de chemin et union par rang
+// https://fr.wikipedia.org/wiki/Union-find
+// Les éléments sont les entiers 0..n
+pub struct EnsemblesDisjoints {
+    parent: Vec<usize>,
+    // Majorant de la hauteur de l'arbre de chaque racine
+    rang: Vec<u8>,
+    nb_ensembles: usize,
+}
+
+impl EnsemblesDisjoints {
+    // n singletons {0}, {1}, .. {n-1}
+    pub fn new(n: usize) -> Self {
+        EnsemblesDisjoints {
+            parent: (0..n).collect(),
+            rang: vec![0; n],
+            nb_ensembles: n,
+        }
+    }
+
+    pub fn nb_ensembles(&self) -> usize {
+        self.nb_ensembles
+    }
+
+    // Représentant de l'ensemble contenant 'element'
+    pub fn trouve(&mut self, element: usize) -> usize {
+        let mut racine = element;
+        while (self.parent[racine] != racine) {
+            racine = self.parent[racine];
+        }
+        // Compression de chemin: tous les éléments parcourus pointent directement vers la racine
+        let mut element_cour = element;
+        while (self.parent[element_cour] != racine) {
+            let suivant = self.parent[element_cour];
+            self.parent[element_cour] = racine;
+            element_cour = suivant;
+        }
+        racine
+    }
+
+    // Réunit les ensembles des 2 éléments; renvoie false s'ils étaient déjà dans le même ensemble
+    pub fn unit(&mut self, element1: usize, element2: usize) -> bool {
+        let racine1 = self.trouve(element1);
+        let racine2 = self.trouve(element2);
+        if (racine1 == racine2) {
+            return false;
+        }
+        // L'arbre le moins haut est rattaché sous l'autre
+        match (self.rang[racine1].cmp(&self.rang[racine2])) {
+            Ordering::Less => self.parent[racine1] = racine2,
+            Ordering::Greater => self.parent[racine2] = racine1,
+            Ordering::Equal => {
+                self.parent[racine1] = racine2;
+                self.rang[racine2] += 1;
+            }
+        }
+        self.nb_ensembles -= 1;
+        true
+    }
+}
+
+// Arbre (ou forêt) couvrant: ses arêtes (source, destination, poids), et la somme de leurs poids
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArbreCouvrant<S, A> {
+    pub aretes: Vec<(S, S, A)>,
+    pub poids_total: A,
+}
+
+// Algorithme de Kruskal: les arêtes sont examinées par poids croissant, et ajoutées à l'arbre
+// si elles relient 2 composantes différentes (testé avec les ensembles disjoints)
+// https://fr.wikipedia.org/wiki/Algorithme_de_Kruskal
+// Complexité: O(E.log(E))
+pub fn kruskal<S, A>(graphe: &GraphePondere<S, A>) -> ArbreCouvrant<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
+    <A as TryFrom<i8>>::Error: Debug,
+{
+    let mut poids_total: A = A::try_from(0i8).expect("Distance nulle manquante pour le type A.");
+    let mut aretes: Vec<(S, S, A)> = graphe.aretes();
+    // Tri stable: à poids égaux, l'ordre d'ajout des arêtes est conservé
+    aretes.sort_by(|(_, _, poids1), (_, _, poids2)| {
+        poids1.partial_cmp(poids2).unwrap_or(Ordering::Equal)
+    });
+
+    let indices: HashMap<S, usize> = graphe
+        .sommets()
+        .iter()
+        .enumerate()
+        .map(|(indice, sommet)| (sommet.clone(), indice))
+        .collect();
+    let mut ensembles = EnsemblesDisjoints::new(graphe.nb_sommets());
+
+    let mut aretes_arbre: Vec<(S, S, A)> = Vec::new();
+    for (source, destination, poids) in aretes {
+        // Un arbre couvrant de n sommets a n-1 arêtes
+        if (ensembles.nb_ensembles() <= 1) {
+            break;
+        }
+        if (ensembles.unit(indices[&source], indices[&destination])) {
+            poids_total = poids_total + poids.clone();
+            aretes_arbre.push((source, destination, poids));
+        }
+    }
+
+    ArbreCouvrant {
+        aretes: aretes_arbre,
+        poids_total,
+    }
+}
+
+// Algorithme de Prim: l'arbre grandit depuis un sommet, en ajoutant à chaque étape
+// l'arête de poids minimal qui le relie à un nouveau sommet (file de priorité sur les arêtes candidates)
+// https://fr.wikipedia.org/wiki/Algorithme_de_Prim
+// Pour une forêt, l'arbre suivant part du premier sommet (dans l'ordre de liste_sommets) non encore atteint.
+// Le graphe doit être non orienté (ex: GraphePondere non orienté, Labyrinthe sans case à sens unique)
+// Complexité: O(E.log(V))
+pub fn prim<G, S, A>(graphe: &G) -> ArbreCouvrant<S, A>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
+    <A as TryFrom<i8>>::Error: Debug,
+{
+    let mut poids_total: A = A::try_from(0i8).expect("Distance nulle manquante pour le type A.");
+    let mut aretes_arbre: Vec<(S, S, A)> = Vec::new();
+    let mut dans_arbre: HashSet<S> = HashSet::new();
+
+    // Arêtes candidates: la priorité est leur poids, le sommet est le couple (sommet de l'arbre, voisin)
+    let mut file: BinaryHeap<ElementFile<(S, S), A>> = BinaryHeap::new();
+    let ajoute_candidates =
+        |file: &mut BinaryHeap<ElementFile<(S, S), A>>, dans_arbre: &HashSet<S>, sommet: &S| {
+            for (voisin, poids) in graphe.liste_voisins_et_distances(sommet) {
+                if (!dans_arbre.contains(&voisin)) {
+                    file.push(ElementFile {
+                        priorite: poids.clone(),
+                        dist: poids,
+                        sommet: (sommet.clone(), voisin),
+                    });
+                }
+            }
+        };
+
+    for racine in graphe.liste_sommets() {
+        if (!dans_arbre.insert(racine.clone())) {
+            continue;
+        }
+        ajoute_candidates(&mut file, &dans_arbre, &racine);
+
+        while let Some(ElementFile {
+            dist: poids,
+            sommet: (sommet, voisin),
+            ..
+        }) = file.pop()
+        {
+            // Arête périmée: le voisin a été atteint depuis par une arête plus légère
+            if (!dans_arbre.insert(voisin.clone())) {
+                continue;
+            }
+            poids_total = poids_total + poids.clone();
+            ajoute_candidates(&mut file, &dans_arbre, &voisin);
+            aretes_arbre.push((sommet, voisin, poids));
+        }
+    }
+
+    ArbreCouvrant {
+        aretes: aretes_arbre,
+        poids_total,
+    }
+}
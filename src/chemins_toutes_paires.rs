@@ -0,0 +1,307 @@
+// Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
+#![allow(unused_parens)]
+
+// Plus courts chemins entre toutes les paires de sommets
+// - Floyd-Warshall: O(V³), simple, adapté aux petits graphes denses
+// - Johnson: Bellman-Ford (repondération), puis Dijkstra depuis chaque sommet: O(V.E.log(V)),
+//   adapté aux graphes peu denses
+// Les deux acceptent les poids négatifs, mais pas les cycles de poids négatif.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+
+use crate::graphes::{
+    resoud_bellman_ford, resoud_dijstra_arbre_complet, Chemin, ErreurGraphe, Sommets, Voisins,
+};
+
+// Distances et premiers pas des plus courts chemins entre toutes les paires de sommets
+// Les matrices sont indexées dans l'ordre des sommets (celui de liste_sommets)
+#[derive(Clone, Debug)]
+pub struct DistancesToutesPaires<S, A> {
+    sommets: Vec<S>,
+    indices: HashMap<S, usize>,
+    // distances[i][j]: distance du sommet i au sommet j (None s'il est inaccessible)
+    distances: Vec<Vec<Option<A>>>,
+    // suivants[i][j]: sommet suivant i sur un plus court chemin de i à j
+    suivants: Vec<Vec<Option<usize>>>,
+}
+
+pub type ResultatToutesPaires<S, A> = Result<DistancesToutesPaires<S, A>, ErreurGraphe<S, A>>;
+
+impl<S, A> DistancesToutesPaires<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: Clone,
+{
+    pub fn sommets(&self) -> &[S] {
+        &self.sommets
+    }
+
+    pub fn matrice_distances(&self) -> &[Vec<Option<A>>] {
+        &self.distances
+    }
+
+    // Distance de 'source' à 'destination' (None si inaccessible)
+    pub fn distance(&self, source: &S, destination: &S) -> Option<A> {
+        let (i, j) = (self.indices.get(source)?, self.indices.get(destination)?);
+        self.distances[*i][*j].clone()
+    }
+
+    // Sommet suivant 'source' sur un plus court chemin vers 'destination'
+    pub fn suivant(&self, source: &S, destination: &S) -> Option<S> {
+        let (i, j) = (self.indices.get(source)?, self.indices.get(destination)?);
+        self.suivants[*i][*j].map(|suivant| self.sommets[suivant].clone())
+    }
+
+    // Plus court chemin de 'source' à 'destination', en suivant les premiers pas
+    pub fn chemin(&self, source: &S, destination: &S) -> Option<Chemin<S, A>> {
+        let (i, j) = (*self.indices.get(source)?, *self.indices.get(destination)?);
+        let cout: A = self.distances[i][j].clone()?;
+        let mut sommets: Vec<S> = vec![self.sommets[i].clone()];
+        let mut cour = i;
+        while (cour != j) {
+            cour = self.suivants[cour][j]?;
+            sommets.push(self.sommets[cour].clone());
+        }
+        Some(Chemin { sommets, cout })
+    }
+}
+
+// Sommets du graphe et leurs indices
+fn indexe_sommets<G, S>(graphe: &G) -> (Vec<S>, HashMap<S, usize>)
+where
+    G: Sommets<S>,
+    S: Eq + Hash + Clone,
+{
+    let sommets: Vec<S> = graphe.liste_sommets();
+    let indices: HashMap<S, usize> = sommets
+        .iter()
+        .enumerate()
+        .map(|(indice, sommet)| (sommet.clone(), indice))
+        .collect();
+    (sommets, indices)
+}
+
+// Algorithme de Floyd-Warshall: on autorise successivement chaque sommet k comme étape intermédiaire
+// https://fr.wikipedia.org/wiki/Algorithme_de_Floyd-Warshall
+// Erreur ErreurGraphe::CycleNegatif si le graphe contient un cycle de poids négatif
+// Complexité: O(V³) en temps, O(V²) en mémoire
+pub fn floyd_warshall<G, S, A>(graphe: &G) -> ResultatToutesPaires<S, A>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
+    <A as TryFrom<i8>>::Error: Debug,
+{
+    let dist_zero: A = A::try_from(0i8).expect("Distance nulle manquante pour le type A.");
+    let (sommets, indices) = indexe_sommets(graphe);
+    let n_sommets = sommets.len();
+
+    let mut distances: Vec<Vec<Option<A>>> = vec![vec![None; n_sommets]; n_sommets];
+    let mut suivants: Vec<Vec<Option<usize>>> = vec![vec![None; n_sommets]; n_sommets];
+    for i in (0..n_sommets) {
+        distances[i][i] = Some(dist_zero.clone());
+        suivants[i][i] = Some(i);
+    }
+    // Arcs directs (le plus léger, s'il y en a plusieurs entre 2 sommets)
+    for (i, sommet) in sommets.iter().enumerate() {
+        for (voisin, poids) in graphe.liste_voisins_et_distances(sommet) {
+            let j = match (indices.get(&voisin)) {
+                Some(j) => *j,
+                None => continue,
+            };
+            if (distances[i][j].as_ref().is_none_or(|dist| &poids < dist)) {
+                distances[i][j] = Some(poids);
+                suivants[i][j] = Some(j);
+            }
+        }
+    }
+
+    for k in (0..n_sommets) {
+        for i in (0..n_sommets) {
+            let dist_ik: A = match (&distances[i][k]) {
+                Some(dist) => dist.clone(),
+                None => continue,
+            };
+            for j in (0..n_sommets) {
+                let dist_kj: A = match (&distances[k][j]) {
+                    Some(dist) => dist.clone(),
+                    None => continue,
+                };
+                let dist_nouveau_possible = dist_ik.clone() + dist_kj;
+                if (distances[i][j]
+                    .as_ref()
+                    .is_none_or(|dist| &dist_nouveau_possible < dist))
+                {
+                    distances[i][j] = Some(dist_nouveau_possible);
+                    suivants[i][j] = suivants[i][k];
+                }
+            }
+        }
+    }
+
+    // Un sommet à distance négative de lui-même est sur un cycle négatif:
+    // Bellman-Ford depuis ce sommet fournit le cycle
+    for i in (0..n_sommets) {
+        if (distances[i][i]
+            .as_ref()
+            .is_some_and(|dist| dist < &dist_zero))
+        {
+            return match (resoud_bellman_ford(graphe, vec![sommets[i].clone()])) {
+                Err(erreur) => Err(erreur),
+                Ok(_) => Err(ErreurGraphe::CycleNegatif(vec![
+                    sommets[i].clone(),
+                    sommets[i].clone(),
+                ])),
+            };
+        }
+    }
+
+    Ok(DistancesToutesPaires {
+        sommets,
+        indices,
+        distances,
+        suivants,
+    })
+}
+
+// Graphe réduit aux sommets de liste_sommets: les arcs vers les autres sommets sont ignorés
+// (comme dans floyd_warshall)
+struct GrapheRestreint<'a, G, S> {
+    graphe: &'a G,
+    sommets: &'a [S],
+    indices: &'a HashMap<S, usize>,
+}
+
+impl<G, S: Clone> Sommets<S> for GrapheRestreint<'_, G, S> {
+    fn liste_sommets(&self) -> Vec<S> {
+        self.sommets.to_vec()
+    }
+}
+
+impl<G, S, A> Voisins<S, A> for GrapheRestreint<'_, G, S>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash,
+    A: PartialOrd + Add,
+{
+    fn liste_voisins_et_distances(&self, sommet: &S) -> Vec<(S, A)> {
+        self.graphe
+            .liste_voisins_et_distances(sommet)
+            .into_iter()
+            .filter(|(voisin, _poids)| self.indices.contains_key(voisin))
+            .collect()
+    }
+}
+
+// Graphe repondéré de l'algorithme de Johnson: w'(u, v) = w(u, v) + h(u) - h(v) >= 0
+struct GrapheRepondere<'a, G, S, A> {
+    graphe: &'a G,
+    potentiels: HashMap<S, A>,
+    dist_zero: A,
+}
+
+impl<G, S, A> Voisins<S, A> for GrapheRepondere<'_, G, S, A>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add<Output = A> + Sub<Output = A> + Clone,
+{
+    fn liste_voisins_et_distances(&self, sommet: &S) -> Vec<(S, A)> {
+        let potentiel_sommet: A = self.potentiels[sommet].clone();
+        self.graphe
+            .liste_voisins_et_distances(sommet)
+            .into_iter()
+            .filter_map(|(voisin, poids)| {
+                // Voisin sans potentiel: absent de liste_sommets, arc ignoré
+                let potentiel_voisin: A = self.potentiels.get(&voisin)?.clone();
+                let poids_repondere = poids + potentiel_sommet.clone() - potentiel_voisin;
+                // Avec des flottants, un poids nul peut devenir très légèrement négatif (arrondis)
+                if (poids_repondere < self.dist_zero) {
+                    Some((voisin, self.dist_zero.clone()))
+                } else {
+                    Some((voisin, poids_repondere))
+                }
+            })
+            .collect()
+    }
+}
+
+// Algorithme de Johnson
+// https://fr.wikipedia.org/wiki/Algorithme_de_Johnson
+// Bellman-Ford, depuis tous les sommets à la fois (équivalent au sommet virtuel relié à tous les autres
+// par des arcs de poids nul), donne des potentiels h tels que les poids repondérés sont positifs,
+// puis Dijkstra (arbre complet) est lancé depuis chaque sommet sur le graphe repondéré.
+// Mêmes sorties et erreurs que floyd_warshall (arcs vers des sommets absents de liste_sommets ignorés)
+// Complexité: O(V.E + V.(V + E).log(V))
+pub fn johnson<G, S, A>(graphe: &G) -> ResultatToutesPaires<S, A>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A> + Sub<Output = A>,
+    <A as TryFrom<i8>>::Error: Debug,
+{
+    let dist_zero: A = A::try_from(0i8).expect("Distance nulle manquante pour le type A.");
+    let (sommets, indices) = indexe_sommets(graphe);
+    let n_sommets = sommets.len();
+
+    let graphe_restreint = GrapheRestreint {
+        graphe,
+        sommets: &sommets,
+        indices: &indices,
+    };
+    let (potentiels, _prec) = resoud_bellman_ford(&graphe_restreint, sommets.clone())?;
+    let graphe_repondere = GrapheRepondere {
+        graphe,
+        potentiels,
+        dist_zero,
+    };
+
+    let mut distances: Vec<Vec<Option<A>>> = vec![vec![None; n_sommets]; n_sommets];
+    let mut suivants: Vec<Vec<Option<usize>>> = vec![vec![None; n_sommets]; n_sommets];
+    for (i, sommet) in sommets.iter().enumerate() {
+        // (les poids repondérés sont positifs: pas d'erreur de poids négatif possible)
+        let (p, prec) = resoud_dijstra_arbre_complet(&graphe_repondere, vec![sommet.clone()])?;
+
+        // Distances d'origine: d(u, v) = d'(u, v) - h(u) + h(v)
+        let potentiel_sommet: A = graphe_repondere.potentiels[sommet].clone();
+        for (destination, dist) in p {
+            let j = indices[&destination];
+            distances[i][j] = Some(
+                dist + graphe_repondere.potentiels[&destination].clone() - potentiel_sommet.clone(),
+            );
+        }
+
+        // Premiers pas: on remonte l'arbre de Dijkstra jusqu'à un sommet dont le premier pas est connu
+        suivants[i][i] = Some(i);
+        for (j, distance) in distances[i].iter().enumerate() {
+            if (distance.is_none()) {
+                continue;
+            }
+            let mut remontee: Vec<usize> = Vec::new();
+            let mut cour = j;
+            while (suivants[i][cour].is_none()) {
+                remontee.push(cour);
+                cour = indices[&prec[&sommets[cour]]];
+            }
+            // Le premier pas est commun à tous les sommets de la remontée
+            let suivant: Option<usize> = if (cour == i) {
+                remontee.last().copied()
+            } else {
+                suivants[i][cour]
+            };
+            for sommet_remontee in remontee {
+                suivants[i][sommet_remontee] = suivant;
+            }
+        }
+    }
+
+    Ok(DistancesToutesPaires {
+        sommets,
+        indices,
+        distances,
+        suivants,
+    })
+}
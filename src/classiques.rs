@@ -145,11 +145,10 @@ pub fn recherche_dichotomique(
     assert!(verif_tableau_croissant(mon_tableau), "(recherche_dichotomique) Erreur: le tableau n'est pas croissant (Nécessite de le trier d'abord).");
 
     if (false) {
-        if index_min_opt.is_some() && index_max_inclus_opt.is_some() {
+        if let (Some(index_min), Some(index_max_inclus)) = (index_min_opt, index_max_inclus_opt) {
             println!(
                 "Appel à recherche_dichotomique: {} {}",
-                index_min_opt.unwrap(),
-                index_max_inclus_opt.unwrap()
+                index_min, index_max_inclus
             );
         } else {
             println!("Appel à recherche_dichotomique");
@@ -212,7 +211,7 @@ pub fn recherche_dichotomique(
     }
 } // fn recherche_dichotomique()
 
-struct HanoiGame {
+pub struct HanoiGame {
     tours: [Vec<u32>; 3],
     verbeux: bool,
 }
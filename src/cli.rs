@@ -0,0 +1,209 @@
+// Module d'analyse de la ligne de commande du binaire 'algos'
+// Implémentation minimale, sans dépendance externe (pas de crate 'clap'):
+//   algos <commande> [arguments positionnels] [--option valeur] [--drapeau]
+// Chaque commande déclare ses options (SpecCommande), ce qui permet de valider les arguments
+// et de générer l'aide (--help) automatiquement.
+
+// Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
+#![allow(unused_parens)]
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+// Description d'une option d'une commande
+// avec_valeur: true -> '--nom valeur', false -> drapeau '--nom'
+pub struct SpecOption {
+    pub nom: &'static str,
+    pub avec_valeur: bool,
+    pub aide: &'static str,
+}
+
+// Description d'une commande (sous-commande du binaire)
+// Le nombre d'arguments positionnels doit être compris entre nb_positionnels_min et nb_positionnels_max (inclus)
+pub struct SpecCommande {
+    pub nom: &'static str,
+    pub resume: &'static str,
+    pub usage: &'static str,
+    pub nb_positionnels_min: usize,
+    pub nb_positionnels_max: usize,
+    pub options: &'static [SpecOption],
+}
+
+// Arguments d'une commande, une fois analysés et validés
+pub struct ArgumentsCommande {
+    positionnels: Vec<String>,
+    options: HashMap<String, String>,
+    drapeaux: Vec<String>,
+}
+
+// Convertit une chaine en valeur de type T, avec un message d'erreur lisible
+fn convertit_valeur<T: FromStr>(nom: &str, valeur: &str) -> Result<T, String> {
+    valeur
+        .parse::<T>()
+        .map_err(|_| format!("Valeur invalide pour '{}': '{}'", nom, valeur))
+}
+
+impl ArgumentsCommande {
+    pub fn nb_positionnels(&self) -> usize {
+        self.positionnels.len()
+    }
+
+    // Argument positionnel d'index 'index' (doit exister: vérifié par nb_positionnels_min)
+    pub fn positionnel<T: FromStr>(&self, index: usize, nom: &str) -> Result<T, String> {
+        match (self.positionnels.get(index)) {
+            Some(valeur) => convertit_valeur(nom, valeur),
+            None => Err(format!("Argument manquant: <{}>", nom)),
+        }
+    }
+
+    // Argument positionnel optionnel, avec une valeur par défaut
+    pub fn positionnel_ou<T: FromStr>(
+        &self,
+        index: usize,
+        nom: &str,
+        defaut: T,
+    ) -> Result<T, String> {
+        match (self.positionnels.get(index)) {
+            Some(valeur) => convertit_valeur(nom, valeur),
+            None => Ok(defaut),
+        }
+    }
+
+    pub fn option<T: FromStr>(&self, nom: &str) -> Result<Option<T>, String> {
+        match (self.options.get(nom)) {
+            Some(valeur) => convertit_valeur(&format!("--{}", nom), valeur).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub fn option_ou<T: FromStr>(&self, nom: &str, defaut: T) -> Result<T, String> {
+        Ok(self.option(nom)?.unwrap_or(defaut))
+    }
+
+    pub fn drapeau(&self, nom: &str) -> bool {
+        self.drapeaux.iter().any(|d| d == nom)
+    }
+}
+
+// Résultat de l'analyse: soit les arguments, soit une demande d'aide
+pub enum Analyse {
+    Arguments(ArgumentsCommande),
+    Aide,
+}
+
+// Analyse les arguments d'une commande (sans le nom du binaire ni celui de la commande)
+// Les valeurs d'options peuvent commencer par '-' (ex: --cx -0.8)
+pub fn analyse_arguments(spec: &SpecCommande, args: &[String]) -> Result<Analyse, String> {
+    let mut positionnels: Vec<String> = Vec::new();
+    let mut options: HashMap<String, String> = HashMap::new();
+    let mut drapeaux: Vec<String> = Vec::new();
+
+    let mut index: usize = 0;
+    while (index < args.len()) {
+        let arg = &args[index];
+        if (arg == "--help" || arg == "-h") {
+            return Ok(Analyse::Aide);
+        }
+
+        if let Some(nom_complet) = arg.strip_prefix("--") {
+            // Syntaxe alternative acceptée: --nom=valeur
+            let (nom, valeur_inline): (&str, Option<&str>) = match (nom_complet.split_once('=')) {
+                Some((nom, valeur)) => (nom, Some(valeur)),
+                None => (nom_complet, None),
+            };
+
+            let spec_option = spec.options.iter().find(|o| o.nom == nom).ok_or(format!(
+                "Option inconnue pour la commande '{}': --{}",
+                spec.nom, nom
+            ))?;
+
+            if (spec_option.avec_valeur) {
+                let valeur: String = match (valeur_inline) {
+                    Some(valeur) => valeur.to_string(),
+                    None => {
+                        index += 1;
+                        args.get(index)
+                            .ok_or(format!("Valeur manquante pour l'option --{}", nom))?
+                            .clone()
+                    }
+                };
+                options.insert(nom.to_string(), valeur);
+            } else {
+                if (valeur_inline.is_some()) {
+                    return Err(format!("L'option --{} n'attend pas de valeur", nom));
+                }
+                drapeaux.push(nom.to_string());
+            }
+        } else {
+            positionnels.push(arg.clone());
+        }
+        index += 1;
+    }
+
+    if (positionnels.len() < spec.nb_positionnels_min) {
+        return Err(format!(
+            "Pas assez d'arguments pour la commande '{}' (attendu: au moins {})",
+            spec.nom, spec.nb_positionnels_min
+        ));
+    }
+    if (positionnels.len() > spec.nb_positionnels_max) {
+        return Err(format!(
+            "Trop d'arguments pour la commande '{}' (attendu: au plus {})",
+            spec.nom, spec.nb_positionnels_max
+        ));
+    }
+
+    Ok(Analyse::Arguments(ArgumentsCommande {
+        positionnels,
+        options,
+        drapeaux,
+    }))
+}
+
+pub fn affiche_aide_generale(nom_binaire: &str, commandes: &[SpecCommande]) {
+    println!("Recueil d'algorithmes implémentés en Rust");
+    println!();
+    println!("Usage: {} <commande> [arguments] [options]", nom_binaire);
+    println!("       {} <commande> --help", nom_binaire);
+    println!();
+    println!("Commandes:");
+    let largeur_nom: usize = commandes.iter().map(|c| c.nom.len()).max().unwrap_or(0);
+    for commande in commandes {
+        println!(
+            "  {:largeur$}  {}",
+            commande.nom,
+            commande.resume,
+            largeur = largeur_nom
+        );
+    }
+}
+
+pub fn affiche_aide_commande(nom_binaire: &str, spec: &SpecCommande) {
+    println!("{}", spec.resume);
+    println!();
+    println!("Usage: {} {} {}", nom_binaire, spec.nom, spec.usage);
+    if (!spec.options.is_empty()) {
+        println!();
+        println!("Options:");
+        let libelles: Vec<String> = spec
+            .options
+            .iter()
+            .map(|o| {
+                if (o.avec_valeur) {
+                    format!("--{} <valeur>", o.nom)
+                } else {
+                    format!("--{}", o.nom)
+                }
+            })
+            .collect();
+        let largeur_libelle: usize = libelles.iter().map(|l| l.len()).max().unwrap_or(0);
+        for (libelle, option) in libelles.iter().zip(spec.options.iter()) {
+            println!(
+                "  {:largeur$}  {}",
+                libelle,
+                option.aide,
+                largeur = largeur_libelle
+            );
+        }
+    }
+}
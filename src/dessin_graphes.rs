@@ -0,0 +1,403 @@
+// Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
+#![allow(unused_parens)]
+
+// Visualisation des graphes implémentant Voisins et Sommets:
+// - export au format DOT de Graphviz (https://graphviz.org/doc/info/lang.html),
+//   à convertir par ex. avec: dot -Tsvg graphe.dot -o graphe.svg
+// - dessin SVG direct (sans outil externe), avec une disposition circulaire ou par forces
+// Un chemin et un ensemble de sommets explorés (ex: sorties de resoud_dijstra) peuvent être mis en évidence.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::erreurs::Erreur;
+use crate::fichiers;
+use crate::fractales::{self, Cercle, Ligne, Rectangle, Texte, Vectorisable};
+use crate::graphes::{Chemin, Sommets, Voisins};
+use crate::probabilites::RngMinstd;
+
+// Sommets et arêtes à mettre en évidence dans les dessins
+#[derive(Clone, Debug)]
+pub struct MiseEnEvidence<S> {
+    // Chemin (ses sommets et les arêtes entre sommets consécutifs)
+    pub chemin: Vec<S>,
+    // Sommets explorés (ex: parcourus par une recherche)
+    pub explores: HashSet<S>,
+}
+
+impl<S> MiseEnEvidence<S>
+where
+    S: Eq + Hash + Clone,
+{
+    // Rien n'est mis en évidence
+    pub fn new() -> Self {
+        MiseEnEvidence {
+            chemin: Vec::new(),
+            explores: HashSet::new(),
+        }
+    }
+
+    // A partir des sorties de resoud_dijstra (ou A*, ..): sommets parcourus 'p', et chemin trouvé
+    pub fn depuis_recherche<A>(p: &HashMap<S, A>, chemin_opt: Option<&Chemin<S, A>>) -> Self {
+        MiseEnEvidence {
+            chemin: chemin_opt
+                .map(|chemin| chemin.sommets.clone())
+                .unwrap_or_default(),
+            explores: p.keys().cloned().collect(),
+        }
+    }
+
+    fn est_sur_chemin(&self, sommet: &S) -> bool {
+        self.chemin.contains(sommet)
+    }
+
+    // L'arête relie 2 sommets consécutifs du chemin (dans un sens quelconque si le graphe n'est pas orienté)
+    fn arete_du_chemin(&self, source: &S, destination: &S, oriente: bool) -> bool {
+        self.chemin.windows(2).any(|paire| {
+            (&paire[0] == source && &paire[1] == destination)
+                || (!oriente && &paire[0] == destination && &paire[1] == source)
+        })
+    }
+}
+
+impl<S> Default for MiseEnEvidence<S>
+where
+    S: Eq + Hash + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Arêtes du graphe (source, destination, poids), en indices dans l'ordre de liste_sommets()
+// Si le graphe n'est pas orienté, chaque arête (listée dans les 2 sens par Voisins) n'est gardée qu'une fois
+fn liste_aretes<G, S, A>(graphe: &G, sommets: &[S], oriente: bool) -> Vec<(usize, usize, A)>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    let indices: HashMap<&S, usize> = sommets
+        .iter()
+        .enumerate()
+        .map(|(indice, sommet)| (sommet, indice))
+        .collect();
+    let mut aretes: Vec<(usize, usize, A)> = Vec::new();
+    // Nombre d'arêtes non orientées déjà vues dans l'autre sens, pour chaque paire de sommets
+    let mut en_attente: HashMap<(usize, usize), usize> = HashMap::new();
+    for (i_source, sommet) in sommets.iter().enumerate() {
+        for (voisin, poids) in graphe.liste_voisins_et_distances(sommet) {
+            let i_destination = match (indices.get(&voisin)) {
+                Some(indice) => *indice,
+                None => continue,
+            };
+            if (!oriente && i_source != i_destination) {
+                // La 2ème apparition d'une arête (depuis l'autre extrémité) est ignorée
+                let compteur = en_attente
+                    .entry((i_source.min(i_destination), i_source.max(i_destination)))
+                    .or_insert(0);
+                if (i_source > i_destination && *compteur > 0) {
+                    *compteur -= 1;
+                    continue;
+                }
+                if (i_source < i_destination) {
+                    *compteur += 1;
+                }
+            }
+            aretes.push((i_source, i_destination, poids));
+        }
+    }
+    aretes
+}
+
+// Identifiant DOT d'un sommet: son nom entre guillemets
+fn identifiant_dot<S: Display>(sommet: &S) -> String {
+    format!(
+        "\"{}\"",
+        sommet
+            .to_string()
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+    )
+}
+
+// Texte DOT du graphe (orienté: digraph, sinon: graph), avec les poids en étiquettes des arêtes
+// Chemin en rouge, ses sommets en orange, et sommets explorés en gris
+pub fn exporte_dot<G, S, A>(
+    graphe: &G,
+    oriente: bool,
+    mise_en_evidence: &MiseEnEvidence<S>,
+) -> String
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone + Display,
+    A: PartialOrd + Add + Display,
+{
+    let sommets: Vec<S> = graphe.liste_sommets();
+    let (type_graphe, lien) = if (oriente) {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+
+    let mut lignes: Vec<String> = vec![format!("{} G {{", type_graphe)];
+    for sommet in sommets.iter() {
+        let attributs = if (mise_en_evidence.est_sur_chemin(sommet)) {
+            " [style=filled, fillcolor=orange]"
+        } else if (mise_en_evidence.explores.contains(sommet)) {
+            " [style=filled, fillcolor=lightgray]"
+        } else {
+            ""
+        };
+        lignes.push(format!("    {}{};", identifiant_dot(sommet), attributs));
+    }
+    for (i_source, i_destination, poids) in liste_aretes(graphe, &sommets, oriente) {
+        let (source, destination) = (&sommets[i_source], &sommets[i_destination]);
+        let attributs = if (mise_en_evidence.arete_du_chemin(source, destination, oriente)) {
+            ", color=red, penwidth=2"
+        } else {
+            ""
+        };
+        lignes.push(format!(
+            "    {} {} {} [label=\"{}\"{}];",
+            identifiant_dot(source),
+            lien,
+            identifiant_dot(destination),
+            poids,
+            attributs
+        ));
+    }
+    lignes.push("}".to_string());
+
+    lignes.join("\n")
+}
+
+pub fn sauve_dot<G, S, A>(
+    graphe: &G,
+    oriente: bool,
+    mise_en_evidence: &MiseEnEvidence<S>,
+    fichier_chemin: &String,
+) -> Result<(), Erreur>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone + Display,
+    A: PartialOrd + Add + Display,
+{
+    fichiers::ecrire_fichier_texte(
+        fichier_chemin,
+        &(exporte_dot(graphe, oriente, mise_en_evidence) + "\n"),
+    )
+}
+
+// Disposition des sommets pour le dessin
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Disposition {
+    // Sommets régulièrement répartis sur un cercle, dans l'ordre de liste_sommets()
+    Circulaire,
+    // Modèle de forces de Fruchterman-Reingold: les sommets se repoussent, les arêtes les attirent
+    // (positions initiales aléatoires, tirées avec 'seed')
+    Forces { iterations: usize, seed: u32 },
+}
+
+// Positions des sommets (dans l'ordre de liste_sommets()), dans le carré [0, 1] x [0, 1]
+pub fn calcule_disposition<G, S, A>(graphe: &G, disposition: Disposition) -> Vec<(f64, f64)>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    let sommets: Vec<S> = graphe.liste_sommets();
+    let n_sommets = sommets.len();
+    let pi = std::f64::consts::PI;
+
+    match (disposition) {
+        Disposition::Circulaire => (0..n_sommets)
+            .map(|indice| {
+                let angle: f64 = 2. * pi * (indice as f64) / (n_sommets as f64) - pi / 2.;
+                (0.5 + 0.5 * angle.cos(), 0.5 + 0.5 * angle.sin())
+            })
+            .collect(),
+        Disposition::Forces { iterations, seed } => {
+            // https://en.wikipedia.org/wiki/Force-directed_graph_drawing
+            let mut rng: RngMinstd = RngMinstd::new(seed);
+            let mut aleatoire = || rng.gen() as f64 / 0x7FFFFFFF as f64;
+            let mut positions: Vec<(f64, f64)> =
+                (0..n_sommets).map(|_| (aleatoire(), aleatoire())).collect();
+            let aretes: Vec<(usize, usize, A)> = liste_aretes(graphe, &sommets, false);
+
+            // Distance idéale entre sommets, et déplacement maximal ('température') qui décroît
+            let k: f64 = f64::sqrt(1. / (n_sommets.max(1) as f64));
+            let temperature_initiale: f64 = 0.1;
+            for iteration in (0..iterations) {
+                let mut deplacements: Vec<(f64, f64)> = vec![(0., 0.); n_sommets];
+                // Répulsion entre toutes les paires de sommets: k² / d
+                for i in (0..n_sommets) {
+                    for j in ((i + 1)..n_sommets) {
+                        let (dx, dy) = (
+                            positions[i].0 - positions[j].0,
+                            positions[i].1 - positions[j].1,
+                        );
+                        let distance: f64 = f64::sqrt(dx * dx + dy * dy).max(1e-6);
+                        let force: f64 = k * k / distance;
+                        let (fx, fy) = (dx / distance * force, dy / distance * force);
+                        deplacements[i] = (deplacements[i].0 + fx, deplacements[i].1 + fy);
+                        deplacements[j] = (deplacements[j].0 - fx, deplacements[j].1 - fy);
+                    }
+                }
+                // Attraction le long des arêtes: d² / k
+                for (i, j, _poids) in aretes.iter() {
+                    let (dx, dy) = (
+                        positions[*i].0 - positions[*j].0,
+                        positions[*i].1 - positions[*j].1,
+                    );
+                    let distance: f64 = f64::sqrt(dx * dx + dy * dy).max(1e-6);
+                    let force: f64 = distance * distance / k;
+                    let (fx, fy) = (dx / distance * force, dy / distance * force);
+                    deplacements[*i] = (deplacements[*i].0 - fx, deplacements[*i].1 - fy);
+                    deplacements[*j] = (deplacements[*j].0 + fx, deplacements[*j].1 + fy);
+                }
+                // Déplacement limité par la température, et sommets maintenus dans le carré
+                let temperature: f64 =
+                    temperature_initiale * (1. - (iteration as f64) / (iterations as f64));
+                for (position, (dx, dy)) in positions.iter_mut().zip(deplacements) {
+                    let norme: f64 = f64::sqrt(dx * dx + dy * dy).max(1e-9);
+                    let pas: f64 = norme.min(temperature);
+                    position.0 = (position.0 + dx / norme * pas).clamp(0., 1.);
+                    position.1 = (position.1 + dy / norme * pas).clamp(0., 1.);
+                }
+            }
+
+            // Mise à l'échelle pour occuper tout le carré
+            let (mut x_min, mut x_max, mut y_min, mut y_max) = (1f64, 0f64, 1f64, 0f64);
+            for (x, y) in positions.iter() {
+                (x_min, x_max) = (x_min.min(*x), x_max.max(*x));
+                (y_min, y_max) = (y_min.min(*y), y_max.max(*y));
+            }
+            let echelle = |valeur: f64, min: f64, max: f64| -> f64 {
+                if (max - min < 1e-9) {
+                    0.5
+                } else {
+                    (valeur - min) / (max - min)
+                }
+            };
+            positions
+                .into_iter()
+                .map(|(x, y)| (echelle(x, x_min, x_max), echelle(y, y_min, y_max)))
+                .collect()
+        }
+    }
+}
+
+// Taille (en pixels) du dessin SVG d'un graphe, rayon des sommets, et marge autour du dessin
+const TAILLE_DESSIN_GRAPHE: u32 = 600;
+const RAYON_SOMMET: u32 = 14;
+const MARGE_DESSIN_GRAPHE: u32 = 40;
+
+// Dessine le graphe en SVG: sommets avec leur nom, arêtes avec leur poids (et une flèche si orienté)
+// Mêmes couleurs que exporte_dot pour la mise en évidence
+pub fn dessine_graphe_svg<G, S, A>(
+    graphe: &G,
+    oriente: bool,
+    disposition: Disposition,
+    mise_en_evidence: &MiseEnEvidence<S>,
+    fichier_chemin: &String,
+) -> Result<(), Erreur>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone + Display,
+    A: PartialOrd + Add + Display,
+{
+    let sommets: Vec<S> = graphe.liste_sommets();
+    let zone: f64 = (TAILLE_DESSIN_GRAPHE - 2 * MARGE_DESSIN_GRAPHE) as f64;
+    let pixels: Vec<(f64, f64)> = calcule_disposition(graphe, disposition)
+        .into_iter()
+        .map(|(x, y)| {
+            (
+                MARGE_DESSIN_GRAPHE as f64 + x * zone,
+                MARGE_DESSIN_GRAPHE as f64 + y * zone,
+            )
+        })
+        .collect();
+
+    let mut figures: Vec<Box<dyn Vectorisable>> = vec![Box::new(Rectangle::new(
+        0,
+        0,
+        TAILLE_DESSIN_GRAPHE,
+        TAILLE_DESSIN_GRAPHE,
+        "white",
+    ))];
+
+    // Arêtes d'abord, pour que les sommets soient dessinés par-dessus
+    let mut etiquettes: Vec<Box<dyn Vectorisable>> = Vec::new();
+    for (i_source, i_destination, poids) in liste_aretes(graphe, &sommets, oriente) {
+        let (source, destination) = (&sommets[i_source], &sommets[i_destination]);
+        let (couleur, epaisseur) =
+            if (mise_en_evidence.arete_du_chemin(source, destination, oriente)) {
+                ("red", 3)
+            } else {
+                ("gray", 1)
+            };
+        let ((x1, y1), (x2, y2)) = (pixels[i_source], pixels[i_destination]);
+        figures.push(Box::new(Ligne::new(
+            x1 as u32, y1 as u32, x2 as u32, y2 as u32, couleur, epaisseur,
+        )));
+
+        let (dx, dy) = (x2 - x1, y2 - y1);
+        let longueur: f64 = f64::sqrt(dx * dx + dy * dy);
+        if (oriente && longueur > (2 * RAYON_SOMMET) as f64) {
+            // Flèche: 2 segments partant du bord du sommet d'arrivée
+            let (ux, uy) = (dx / longueur, dy / longueur);
+            let (px, py) = (x2 - ux * RAYON_SOMMET as f64, y2 - uy * RAYON_SOMMET as f64);
+            for sens in [-1., 1.] {
+                let (fx, fy) = (
+                    px - 10. * ux + sens * 5. * uy,
+                    py - 10. * uy - sens * 5. * ux,
+                );
+                figures.push(Box::new(Ligne::new(
+                    px as u32, py as u32, fx as u32, fy as u32, couleur, epaisseur,
+                )));
+            }
+        }
+        etiquettes.push(Box::new(Texte::new(
+            ((x1 + x2) / 2.) as u32,
+            ((y1 + y2) / 2.) as u32,
+            &poids.to_string(),
+            11,
+            "blue",
+        )));
+    }
+    figures.extend(etiquettes);
+
+    for (sommet, (x, y)) in sommets.iter().zip(pixels) {
+        let couleur = if (mise_en_evidence.est_sur_chemin(sommet)) {
+            "orange"
+        } else if (mise_en_evidence.explores.contains(sommet)) {
+            "lightgray"
+        } else {
+            "white"
+        };
+        figures.push(Box::new(Cercle::new(
+            x as u32,
+            y as u32,
+            RAYON_SOMMET,
+            couleur,
+            "black",
+        )));
+        figures.push(Box::new(Texte::new(
+            x as u32,
+            y as u32,
+            &sommet.to_string(),
+            12,
+            "black",
+        )));
+    }
+
+    fractales::cree_fichier_svg(
+        fichier_chemin,
+        TAILLE_DESSIN_GRAPHE,
+        TAILLE_DESSIN_GRAPHE,
+        &figures,
+    )
+}
@@ -455,7 +455,7 @@ pub fn calcule_temps_de_vol_et_altitude_max(n: u64) -> (u64, u64) {
 
     let mut n_cour: u64 = n;
     while (n_cour != 1) {
-        if ((n_cour % 2) == 0) {
+        if (n_cour.is_multiple_of(2)) {
             n_cour /= 2;
         } else {
             n_cour = 3 * n_cour + 1;
@@ -492,7 +492,8 @@ pub fn calcule_temps_de_vol_max(n_max: u64) -> (u64, u64) {
 // Meme calcul que la fonction au dessus (calcule_temps_de_vol_max)
 // Implémentation en assembleur
 // TODO : Ne compile pas: A debugger:  "error: Undefined temporary symbol .Ltmp8"
-pub fn _calcule_temps_de_vol_max_asm(n_max: u64) -> (u64, u64) {
+// (Non publique: sinon la fonction est compilée avec la bibliothèque, et la fait échouer)
+fn _calcule_temps_de_vol_max_asm(n_max: u64) -> (u64, u64) {
     let mut temps_de_vol_max: u64;
     let mut temps_de_vol_max_index: u64;
 
@@ -0,0 +1,83 @@
+// Type d'erreur commun au crate
+// Les fonctions pouvant échouer pour une raison extérieure au programme (fichier absent, droits..)
+// renvoient un Result<T, Erreur> plutôt que de paniquer, afin que l'appelant puisse réagir.
+
+// Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
+#![allow(unused_parens)]
+
+use std::fmt::{Display, Formatter};
+use std::io::{self, ErrorKind};
+
+#[derive(Debug)]
+pub enum Erreur {
+    // Le fichier (ou dossier) n'existe pas
+    FichierIntrouvable(String),
+    // Droits insuffisants pour lire / écrire le fichier
+    PermissionRefusee(String),
+    // Le fichier texte n'est pas encodé en UTF-8
+    Utf8Invalide(String),
+    // Ni fichier régulier, ni dossier, ni lien symbolique
+    TypeFichierInconnu(String),
+    // Autre erreur d'entrée/sortie, avec le chemin concerné
+    EntreeSortie(String, io::Error),
+    // Contenu de fichier mal formé: chemin, numéro de ligne (à partir de 1) et description
+    FormatInvalide(String, usize, String),
+    // Erreur d'un algorithme de graphe (ErreurGraphe: poids négatif, cycle..), avec sa description
+    Graphe(String),
+}
+
+impl Erreur {
+    // Convertit une erreur d'entrée/sortie de la bibliothèque standard,
+    // en conservant le chemin du fichier concerné
+    pub fn depuis_io(erreur: io::Error, chemin: &str) -> Self {
+        match (erreur.kind()) {
+            ErrorKind::NotFound => Erreur::FichierIntrouvable(chemin.to_string()),
+            ErrorKind::PermissionDenied => Erreur::PermissionRefusee(chemin.to_string()),
+            // fs::read_to_string renvoie InvalidData si le contenu n'est pas de l'UTF-8
+            ErrorKind::InvalidData => Erreur::Utf8Invalide(chemin.to_string()),
+            _ => Erreur::EntreeSortie(chemin.to_string(), erreur),
+        }
+    }
+}
+
+impl Display for Erreur {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self) {
+            Erreur::FichierIntrouvable(chemin) => write!(f, "Fichier introuvable: {}", chemin),
+            Erreur::PermissionRefusee(chemin) => write!(f, "Permission refusée: {}", chemin),
+            Erreur::Utf8Invalide(chemin) => {
+                write!(f, "Le fichier n'est pas encodé en UTF-8: {}", chemin)
+            }
+            Erreur::TypeFichierInconnu(chemin) => {
+                write!(f, "Type de fichier non reconnu: {}", chemin)
+            }
+            Erreur::EntreeSortie(chemin, erreur) => {
+                write!(f, "Erreur d'entrée/sortie sur {}: {}", chemin, erreur)
+            }
+            Erreur::FormatInvalide(chemin, ligne, message) => {
+                write!(
+                    f,
+                    "Format invalide dans {} (ligne {}): {}",
+                    chemin, ligne, message
+                )
+            }
+            Erreur::Graphe(message) => write!(f, "Erreur de graphe: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Erreur {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match (self) {
+            Erreur::EntreeSortie(_, erreur) => Some(erreur),
+            _ => None,
+        }
+    }
+}
+
+// Permet d'utiliser l'opérateur '?' dans les fonctions renvoyant un Result<_, String> (ex: main.rs)
+impl From<Erreur> for String {
+    fn from(erreur: Erreur) -> String {
+        erreur.to_string()
+    }
+}
@@ -1,34 +1,38 @@
 // Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
 #![allow(unused_parens)]
 
+// Les fonctions de ce module ne paniquent pas en cas d'erreur d'entrée/sortie:
+// elles renvoient un Result, avec le type d'erreur du crate (voir erreurs.rs)
+
 use std::fs::File;
 use std::fs::{self, read_dir, Permissions};
-use std::io::ErrorKind;
 use std::io::Read;
 use std::io::Write;
 use std::path::Path;
 use std::time::SystemTime;
 
+use crate::erreurs::Erreur;
+
 // Fonction pour tester l'existence d'un fichier sur disque dur
 pub fn test_existence_fichier(fichier_chemin: &String) -> bool {
     Path::new(&fichier_chemin).exists()
 }
 
 // Fonction pour lire un fichier texte, et renvoyer son contenu sour forme de String
-pub fn lire_fichier_texte(fichier_chemin: &String) -> String {
+pub fn lire_fichier_texte(fichier_chemin: &String) -> Result<String, Erreur> {
     // Ouvrir le fichier texte
     // Voir: https://doc.rust-lang.org/book/ch12-02-reading-a-file.html
     // et: https://doc.rust-lang.org/std/fs/struct.File.html
-    fs::read_to_string(fichier_chemin).expect("Fichier introuvable")
+    fs::read_to_string(fichier_chemin).map_err(|e| Erreur::depuis_io(e, fichier_chemin))
 }
 
 // Fonction pour lire un fichier texte, et renvoyer son contenu ligne par ligne en Vec<String>
 pub fn lire_fichier_texte_lignes(
     fichier_chemin: &String,
     separateur_opt: Option<&str>,
-) -> Vec<String> {
+) -> Result<Vec<String>, Erreur> {
     // Lire le fichier texte
-    let contenu = lire_fichier_texte(fichier_chemin);
+    let contenu = lire_fichier_texte(fichier_chemin)?;
 
     let separateur_defaut = "\n";
     let separateur = if let Some(elem) = separateur_opt {
@@ -36,59 +40,55 @@ pub fn lire_fichier_texte_lignes(
     } else {
         separateur_defaut
     };
-    contenu.split(separateur).map(|s| s.to_string()).collect()
+    Ok(contenu.split(separateur).map(|s| s.to_string()).collect())
 }
 
 // Ecriture d'un fichier texte
-pub fn ecrire_fichier_texte(fichier_chemin: &String, contenu: &String) {
+pub fn ecrire_fichier_texte(fichier_chemin: &String, contenu: &String) -> Result<(), Erreur> {
     // Voir: https://doc.rust-lang.org/std/fs/struct.File.html
-    let mut fichier = File::create(fichier_chemin).expect("Erreur: N'a pas pu créer le fichier.");
+    let mut fichier =
+        File::create(fichier_chemin).map_err(|e| Erreur::depuis_io(e, fichier_chemin))?;
     fichier
         .write_all(contenu.as_bytes())
-        .expect("Erreur: N'a pas pu écrire dans le fichier.");
+        .map_err(|e| Erreur::depuis_io(e, fichier_chemin))?;
     println!("Fichier écrit: {}", fichier_chemin);
+    Ok(())
 }
 
-pub fn ecrire_fichier_texte_lignes(fichier_chemin: &String, contenu_vec: &[String]) {
+pub fn ecrire_fichier_texte_lignes(
+    fichier_chemin: &String,
+    contenu_vec: &[String],
+) -> Result<(), Erreur> {
     ecrire_fichier_texte(fichier_chemin, &contenu_vec.join("\n"))
 }
 
 // Inspiré de: https://www.reddit.com/r/rust/comments/dekpl5/how_to_read_binary_data_from_a_file_into_a_vecu8/?rdt=46881
 // Lire un fichier binaire
-pub fn lire_fichier_binaire(fichier_chemin: &String) -> Vec<u8> {
-    let mut fichier = File::open(fichier_chemin).expect("Fichier introuvable");
-    let taille: usize = donne_taille_fichier(fichier_chemin) as usize;
+pub fn lire_fichier_binaire(fichier_chemin: &String) -> Result<Vec<u8>, Erreur> {
+    let mut fichier =
+        File::open(fichier_chemin).map_err(|e| Erreur::depuis_io(e, fichier_chemin))?;
+    let taille: usize = donne_taille_fichier(fichier_chemin)? as usize;
     let mut buffer: Vec<u8> = vec![0; taille];
     fichier
         .read_exact(&mut buffer)
-        .expect("Dépassement de capacité.");
-    buffer
+        .map_err(|e| Erreur::depuis_io(e, fichier_chemin))?;
+    Ok(buffer)
 }
 
 // Ecrire une fichier binaire
-pub fn ecrire_fichier_binaire(fichier_chemin: &String, contenu: &Vec<u8>) {
-    std::fs::write(fichier_chemin, contenu).expect("Erreur: N'a pas pu écrire le fichier binaire.");
+pub fn ecrire_fichier_binaire(fichier_chemin: &String, contenu: &Vec<u8>) -> Result<(), Erreur> {
+    std::fs::write(fichier_chemin, contenu).map_err(|e| Erreur::depuis_io(e, fichier_chemin))
 }
 
 // Voir: https://stackoverflow.com/questions/66577339/collect-file-names-into-vecstr
 // Lister tous les élements d'un dossier
-pub fn liste_dossier(dossier_chemin: &String) -> Vec<String> {
-    let paths_res = read_dir(dossier_chemin);
-    match (paths_res) {
-        Err(erreur) if erreur.kind() == ErrorKind::NotFound => {
-            panic!("Dossier non trouvé");
-        }
-        Err(erreur) => {
-            panic!("Erreur inattendue: {:?}", erreur)
-        }
-        Ok(resultat) => {
-            resultat
-                .filter_map(|e| e.ok())
-                .map(|e| e.path().to_string_lossy().into_owned())
-                //.collect()
-                .collect::<Vec<_>>()
-        }
-    }
+pub fn liste_dossier(dossier_chemin: &String) -> Result<Vec<String>, Erreur> {
+    let resultat = read_dir(dossier_chemin).map_err(|e| Erreur::depuis_io(e, dossier_chemin))?;
+    Ok(resultat
+        .filter_map(|e| e.ok())
+        .map(|e| e.path().to_string_lossy().into_owned())
+        //.collect()
+        .collect::<Vec<_>>())
 }
 
 #[derive(Debug)]
@@ -109,8 +109,9 @@ pub struct InfosFichier {
 
 // Donner les informations essentielles d'un fichier:
 // sa taille, sa date de modif, son type (fichier, dossier, lien symbolique) et ses permissions
-pub fn donne_infos_fichier(fichier_chemin: &String) -> InfosFichier {
-    let metadata = fs::metadata(fichier_chemin).expect("Fichier non trouvé.");
+pub fn donne_infos_fichier(fichier_chemin: &String) -> Result<InfosFichier, Erreur> {
+    let metadata =
+        fs::metadata(fichier_chemin).map_err(|e| Erreur::depuis_io(e, fichier_chemin))?;
 
     let file_type = metadata.file_type();
     let mut type_fichier_opt: Option<TypeFichier> = None;
@@ -123,26 +124,25 @@ pub fn donne_infos_fichier(fichier_chemin: &String) -> InfosFichier {
     if (file_type.is_symlink()) {
         type_fichier_opt = Some(TypeFichier::LienSymbolique);
     }
-    if (type_fichier_opt.is_none()) {
-        panic!("Type de fichier non reconnu: {}", fichier_chemin);
-    }
+    let type_fichier: TypeFichier =
+        type_fichier_opt.ok_or(Erreur::TypeFichierInconnu(fichier_chemin.clone()))?;
 
     let permissions: Permissions = metadata.permissions();
     let date_modif: SystemTime = metadata
         .modified()
-        .expect("Erreur avec metadata.modified()");
+        .map_err(|e| Erreur::depuis_io(e, fichier_chemin))?;
     let taille: u64 = metadata.len();
 
-    InfosFichier {
-        type_fichier: type_fichier_opt.unwrap(),
+    Ok(InfosFichier {
+        type_fichier,
         permissions,
         date_modif,
         taille,
-    }
+    })
 }
 
-pub fn donne_taille_fichier(fichier_chemin: &String) -> u64 {
-    fs::metadata(fichier_chemin)
-        .expect("Fichier non trouvé.")
-        .len()
+pub fn donne_taille_fichier(fichier_chemin: &String) -> Result<u64, Erreur> {
+    Ok(fs::metadata(fichier_chemin)
+        .map_err(|e| Erreur::depuis_io(e, fichier_chemin))?
+        .len())
 }
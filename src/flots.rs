@@ -0,0 +1,357 @@
+// Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
+#![allow(unused_parens)]
+
+// Flot maximal et coupe minimale dans un réseau de capacités
+// https://fr.wikipedia.org/wiki/Problème_de_flot_maximum
+// Le réseau est un GraphePondere dont les poids sont les capacités des arcs.
+// Généricité (comme pour resoud_dijstra): A = type des capacités (entiers, flottants..)
+// Les arêtes sont des arcs (source -> destination), même si le graphe n'est pas orienté:
+// pour une capacité dans les 2 sens, ajouter les 2 arcs.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+
+use crate::graphe_pondere::GraphePondere;
+use crate::graphes::ErreurGraphe;
+
+// Résultat d'un calcul de flot maximal
+#[derive(Clone, Debug)]
+pub struct FlotMaximal<S, A> {
+    // Valeur du flot: quantité totale partant de la source (= arrivant au puits)
+    pub valeur: A,
+    // Flot sur chaque arc (source, destination, flot), dans l'ordre de GraphePondere::aretes()
+    pub flots: Vec<(S, S, A)>,
+    // Coupe minimale: sommets accessibles depuis la source dans le réseau résiduel..
+    pub cote_source: HashSet<S>,
+    // .. et arcs saturés qui les séparent des autres sommets (la somme de leurs capacités vaut 'valeur')
+    pub coupe: Vec<(S, S, A)>,
+}
+
+pub type ResultatFlot<S, A> = Result<FlotMaximal<S, A>, ErreurGraphe<S, A>>;
+
+// Réseau résiduel: l'arc i du graphe donne l'arc résiduel 2i (capacité restante)
+// et l'arc inverse 2i+1 (flot déjà passé, qui peut être annulé). L'inverse de l'arc j est donc j ^ 1.
+struct ReseauResiduel<A> {
+    destinations: Vec<usize>,
+    capacites: Vec<A>,
+    // Pour chaque sommet (indice), les arcs résiduels qui en partent
+    adjacence: Vec<Vec<usize>>,
+    zero: A,
+}
+
+impl<A> ReseauResiduel<A>
+where
+    A: PartialOrd + Add<Output = A> + Sub<Output = A> + Clone,
+{
+    // Augmente le flot de 'quantite' le long de l'arc résiduel 'arc'
+    fn pousse(&mut self, arc: usize, quantite: A) {
+        self.capacites[arc] = self.capacites[arc].clone() - quantite.clone();
+        self.capacites[arc ^ 1] = self.capacites[arc ^ 1].clone() + quantite;
+    }
+
+    fn est_utilisable(&self, arc: usize) -> bool {
+        self.capacites[arc] > self.zero
+    }
+
+    // Parcours en largeur depuis la source par les arcs non saturés:
+    // distance (en nombre d'arcs) de chaque sommet atteint, et arc par lequel il a été atteint
+    fn parcours_largeur(&self, i_source: usize) -> (Vec<Option<usize>>, Vec<Option<usize>>) {
+        let mut niveaux: Vec<Option<usize>> = vec![None; self.adjacence.len()];
+        let mut arcs_parents: Vec<Option<usize>> = vec![None; self.adjacence.len()];
+        niveaux[i_source] = Some(0);
+        let mut file: VecDeque<usize> = VecDeque::from([i_source]);
+        while let Some(sommet) = file.pop_front() {
+            let niveau = niveaux[sommet].expect("Sommet non atteint.");
+            for arc in self.adjacence[sommet].iter() {
+                let voisin = self.destinations[*arc];
+                if (niveaux[voisin].is_none() && self.est_utilisable(*arc)) {
+                    niveaux[voisin] = Some(niveau + 1);
+                    arcs_parents[voisin] = Some(*arc);
+                    file.push_back(voisin);
+                }
+            }
+        }
+        (niveaux, arcs_parents)
+    }
+}
+
+// Minimum de 2 valeurs seulement PartialOrd
+fn minimum<A: PartialOrd>(a: A, b: A) -> A {
+    if (b < a) {
+        b
+    } else {
+        a
+    }
+}
+
+// Construction du réseau résiduel, puis calcul du flot par 'augmente', et extraction des résultats
+fn flot_maximal<S, A, F>(
+    graphe: &GraphePondere<S, A>,
+    source: &S,
+    puits: &S,
+    augmente: F,
+) -> ResultatFlot<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add<Output = A> + Sub<Output = A> + TryFrom<i8> + Clone + Debug,
+    <A as TryFrom<i8>>::Error: Debug,
+    F: Fn(&mut ReseauResiduel<A>, usize, usize) -> A,
+{
+    let zero: A = A::try_from(0i8).expect("Distance nulle manquante pour le type A.");
+
+    let sommets: &[S] = graphe.sommets();
+    let indices: HashMap<S, usize> = sommets
+        .iter()
+        .enumerate()
+        .map(|(indice, sommet)| (sommet.clone(), indice))
+        .collect();
+    let aretes: Vec<(S, S, A)> = graphe.aretes();
+
+    let mut reseau = ReseauResiduel {
+        destinations: Vec::new(),
+        capacites: Vec::new(),
+        adjacence: vec![Vec::new(); sommets.len()],
+        zero: zero.clone(),
+    };
+    for (sommet, voisin, capacite) in aretes.iter() {
+        if (capacite < &zero) {
+            return Err(ErreurGraphe::PoidsNegatif(
+                sommet.clone(),
+                voisin.clone(),
+                capacite.clone(),
+            ));
+        }
+        let (i_sommet, i_voisin) = (indices[sommet], indices[voisin]);
+        reseau.adjacence[i_sommet].push(reseau.destinations.len());
+        reseau.destinations.push(i_voisin);
+        reseau.capacites.push(capacite.clone());
+        reseau.adjacence[i_voisin].push(reseau.destinations.len());
+        reseau.destinations.push(i_sommet);
+        reseau.capacites.push(zero.clone());
+    }
+
+    // Source ou puits absent du graphe, ou source = puits: flot nul
+    let valeur: A = match (indices.get(source), indices.get(puits)) {
+        (Some(i_source), Some(i_puits)) if (i_source != i_puits) => {
+            augmente(&mut reseau, *i_source, *i_puits)
+        }
+        _ => zero.clone(),
+    };
+
+    // Le flot d'un arc est la capacité de son arc inverse dans le réseau résiduel
+    let flots: Vec<(S, S, A)> = aretes
+        .iter()
+        .enumerate()
+        .map(|(i_arete, (sommet, voisin, _capacite))| {
+            (
+                sommet.clone(),
+                voisin.clone(),
+                reseau.capacites[2 * i_arete + 1].clone(),
+            )
+        })
+        .collect();
+
+    // Coupe minimale: les sommets encore accessibles depuis la source
+    let cote_source: HashSet<S> = match (indices.get(source)) {
+        Some(i_source) => {
+            let (niveaux, _arcs_parents) = reseau.parcours_largeur(*i_source);
+            sommets
+                .iter()
+                .enumerate()
+                .filter(|(indice, _sommet)| niveaux[*indice].is_some())
+                .map(|(_indice, sommet)| sommet.clone())
+                .collect()
+        }
+        None => HashSet::from([source.clone()]),
+    };
+    // (source = puits: rien à séparer, coupe vide)
+    let coupe: Vec<(S, S, A)> = aretes
+        .into_iter()
+        .filter(|(sommet, voisin, _capacite)| {
+            source != puits && cote_source.contains(sommet) && !cote_source.contains(voisin)
+        })
+        .collect();
+
+    Ok(FlotMaximal {
+        valeur,
+        flots,
+        cote_source,
+        coupe,
+    })
+}
+
+// Algorithme d'Edmonds-Karp: Ford-Fulkerson, où le chemin augmentant est le plus court
+// (en nombre d'arcs), trouvé par un parcours en largeur du réseau résiduel
+// https://fr.wikipedia.org/wiki/Algorithme_d%27Edmonds-Karp
+// Erreur ErreurGraphe::PoidsNegatif si une capacité est négative
+// Complexité: O(V.E²)
+pub fn edmonds_karp<S, A>(graphe: &GraphePondere<S, A>, source: &S, puits: &S) -> ResultatFlot<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add<Output = A> + Sub<Output = A> + TryFrom<i8> + Clone + Debug,
+    <A as TryFrom<i8>>::Error: Debug,
+{
+    flot_maximal(graphe, source, puits, |reseau, i_source, i_puits| {
+        let mut valeur: A = reseau.zero.clone();
+        loop {
+            let (_niveaux, arcs_parents) = reseau.parcours_largeur(i_source);
+            if (arcs_parents[i_puits].is_none()) {
+                return valeur;
+            }
+
+            // Arcs du chemin augmentant, remonté depuis le puits, et capacité minimale le long du chemin
+            let mut chemin: Vec<usize> = Vec::new();
+            let mut sommet = i_puits;
+            while let Some(arc) = arcs_parents[sommet] {
+                chemin.push(arc);
+                sommet = reseau.destinations[arc ^ 1];
+            }
+            let goulot: A = chemin
+                .iter()
+                .map(|arc| reseau.capacites[*arc].clone())
+                .reduce(minimum)
+                .expect("Chemin augmentant vide.");
+
+            for arc in chemin {
+                reseau.pousse(arc, goulot.clone());
+            }
+            valeur = valeur + goulot;
+        }
+    })
+}
+
+// Algorithme de Dinic: à chaque phase, un parcours en largeur numérote les sommets par niveau,
+// puis on sature tous les plus courts chemins augmentants (flot bloquant) par des parcours en profondeur
+// qui ne suivent que les arcs d'un niveau au suivant.
+// https://fr.wikipedia.org/wiki/Algorithme_de_Dinic
+// Mêmes sorties et erreurs que edmonds_karp
+// Complexité: O(V².E), et O(E.√V) pour les graphes à capacités unitaires (ex: couplage biparti)
+pub fn dinic<S, A>(graphe: &GraphePondere<S, A>, source: &S, puits: &S) -> ResultatFlot<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add<Output = A> + Sub<Output = A> + TryFrom<i8> + Clone + Debug,
+    <A as TryFrom<i8>>::Error: Debug,
+{
+    flot_maximal(graphe, source, puits, |reseau, i_source, i_puits| {
+        let mut valeur: A = reseau.zero.clone();
+        loop {
+            let (niveaux, _arcs_parents) = reseau.parcours_largeur(i_source);
+            if (niveaux[i_puits].is_none()) {
+                return valeur;
+            }
+
+            // Prochain arc à essayer pour chaque sommet: les arcs sans issue ne sont pas réessayés
+            let mut prochains_arcs: Vec<usize> = vec![0; reseau.adjacence.len()];
+            // Parcours en profondeur itératif: pile des arcs du chemin en cours depuis la source
+            let mut chemin: Vec<usize> = Vec::new();
+            loop {
+                let sommet = match (chemin.last()) {
+                    Some(arc) => reseau.destinations[*arc],
+                    None => i_source,
+                };
+
+                if (sommet == i_puits) {
+                    // Chemin augmentant trouvé: on le sature, puis on repart de la source
+                    let goulot: A = chemin
+                        .iter()
+                        .map(|arc| reseau.capacites[*arc].clone())
+                        .reduce(minimum)
+                        .expect("Chemin augmentant vide.");
+                    for arc in chemin.drain(..) {
+                        reseau.pousse(arc, goulot.clone());
+                    }
+                    valeur = valeur + goulot;
+                    continue;
+                }
+
+                let arc_suivant_opt = reseau.adjacence[sommet][prochains_arcs[sommet]..]
+                    .iter()
+                    .position(|arc| {
+                        let voisin = reseau.destinations[*arc];
+                        reseau.est_utilisable(*arc)
+                            && niveaux[voisin] == niveaux[sommet].map(|niveau| niveau + 1)
+                    });
+                match (arc_suivant_opt) {
+                    Some(decalage) => {
+                        prochains_arcs[sommet] += decalage;
+                        chemin.push(reseau.adjacence[sommet][prochains_arcs[sommet]]);
+                    }
+                    None => {
+                        // Impasse: le sommet est abandonné pour cette phase
+                        prochains_arcs[sommet] = reseau.adjacence[sommet].len();
+                        match (chemin.pop()) {
+                            Some(arc) => {
+                                // L'arc menant à l'impasse est abandonné
+                                let precedent = reseau.destinations[arc ^ 1];
+                                prochains_arcs[precedent] += 1;
+                            }
+                            // Plus de chemin depuis la source: le flot est bloquant
+                            None => break,
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+// Sommets du réseau construit pour le couplage biparti
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum SommetBiparti<S, T> {
+    Source,
+    Gauche(S),
+    Droite(T),
+    Puits,
+}
+
+// Couplage maximal d'un graphe biparti: plus grand ensemble d'arêtes sans sommet commun
+// https://fr.wikipedia.org/wiki/Couplage_(théorie_des_graphes)
+// Entrée: les arêtes (gauche, droite) du graphe (ex: candidats et postes qu'ils peuvent occuper)
+// Sortie: les arêtes du couplage, dans l'ordre des arêtes données
+// Calculé par un flot maximal (Dinic) de capacités 1: source -> gauche -> droite -> puits
+pub fn couplage_biparti<S, T>(aretes: &[(S, T)]) -> Vec<(S, T)>
+where
+    S: Eq + Hash + Clone,
+    T: Eq + Hash + Clone,
+{
+    let mut reseau: GraphePondere<SommetBiparti<S, T>, i32> = GraphePondere::new(true);
+    let mut gauches: HashSet<&S> = HashSet::new();
+    let mut droites: HashSet<&T> = HashSet::new();
+    for (gauche, droite) in aretes {
+        if (gauches.insert(gauche)) {
+            reseau.ajoute_arete(
+                SommetBiparti::Source,
+                SommetBiparti::Gauche(gauche.clone()),
+                1,
+            );
+        }
+        if (droites.insert(droite)) {
+            reseau.ajoute_arete(
+                SommetBiparti::Droite(droite.clone()),
+                SommetBiparti::Puits,
+                1,
+            );
+        }
+        reseau.ajoute_arete(
+            SommetBiparti::Gauche(gauche.clone()),
+            SommetBiparti::Droite(droite.clone()),
+            1,
+        );
+    }
+
+    // Les capacités sont positives: pas d'erreur possible
+    let Ok(flot) = dinic(&reseau, &SommetBiparti::Source, &SommetBiparti::Puits) else {
+        unreachable!("Capacité négative dans le couplage biparti.");
+    };
+    flot.flots
+        .into_iter()
+        .filter_map(|(sommet, voisin, flot)| match (sommet, voisin) {
+            (SommetBiparti::Gauche(gauche), SommetBiparti::Droite(droite)) if (flot > 0) => {
+                Some((gauche, droite))
+            }
+            _ => None,
+        })
+        .collect()
+}
@@ -3,6 +3,7 @@
 // Ne pas faire de warning si des fonctions ne sont pas appelées
 #![warn(dead_code)]
 
+use crate::erreurs::Erreur;
 use crate::fichiers;
 use std::cmp::min;
 use std::fmt::Write;
@@ -43,12 +44,127 @@ impl Vectorisable for Ligne {
     }
 }
 
+impl Ligne {
+    pub fn new(x1: u32, y1: u32, x2: u32, y2: u32, couleur: &str, epaisseur: u32) -> Self {
+        Ligne {
+            x1,
+            y1,
+            x2,
+            y2,
+            couleur: couleur.to_string(),
+            epaisseur,
+        }
+    }
+}
+
+// Rectangle plein (ex: case d'un labyrinthe)
+#[derive(Clone)]
+pub struct Rectangle {
+    x: u32,
+    y: u32,
+    largeur: u32,
+    hauteur: u32,
+    couleur: String,
+}
+
+impl Rectangle {
+    pub fn new(x: u32, y: u32, largeur: u32, hauteur: u32, couleur: &str) -> Self {
+        Rectangle {
+            x,
+            y,
+            largeur,
+            hauteur,
+            couleur: couleur.to_string(),
+        }
+    }
+}
+
+impl Vectorisable for Rectangle {
+    fn convertit_en_syntaxe_svg(&self) -> String {
+        // Exemple: <rect x="0" y="0" width="10" height="10" fill="black"/>
+        format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+            self.x, self.y, self.largeur, self.hauteur, self.couleur
+        )
+    }
+}
+
+// Cercle plein, avec un contour (ex: sommet d'un graphe)
+#[derive(Clone)]
+pub struct Cercle {
+    x: u32,
+    y: u32,
+    rayon: u32,
+    couleur: String,
+    couleur_contour: String,
+}
+
+impl Cercle {
+    pub fn new(x: u32, y: u32, rayon: u32, couleur: &str, couleur_contour: &str) -> Self {
+        Cercle {
+            x,
+            y,
+            rayon,
+            couleur: couleur.to_string(),
+            couleur_contour: couleur_contour.to_string(),
+        }
+    }
+}
+
+impl Vectorisable for Cercle {
+    fn convertit_en_syntaxe_svg(&self) -> String {
+        // Exemple: <circle cx="50" cy="50" r="10" fill="white" stroke="black"/>
+        format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\" stroke=\"{}\"/>",
+            self.x, self.y, self.rayon, self.couleur, self.couleur_contour
+        )
+    }
+}
+
+// Texte centré sur un point (ex: nom d'un sommet)
+#[derive(Clone)]
+pub struct Texte {
+    x: u32,
+    y: u32,
+    texte: String,
+    taille: u32,
+    couleur: String,
+}
+
+impl Texte {
+    pub fn new(x: u32, y: u32, texte: &str, taille: u32, couleur: &str) -> Self {
+        Texte {
+            x,
+            y,
+            texte: texte.to_string(),
+            taille,
+            couleur: couleur.to_string(),
+        }
+    }
+}
+
+impl Vectorisable for Texte {
+    fn convertit_en_syntaxe_svg(&self) -> String {
+        // Exemple: <text x="50" y="50" font-size="12" fill="black" text-anchor="middle" dominant-baseline="central">a</text>
+        // Les caractères spéciaux du XML sont échappés
+        let texte: String = self
+            .texte
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        format!(
+            "<text x=\"{}\" y=\"{}\" font-size=\"{}\" fill=\"{}\" text-anchor=\"middle\" dominant-baseline=\"central\">{}</text>",
+            self.x, self.y, self.taille, self.couleur, texte
+        )
+    }
+}
+
 pub fn cree_fichier_svg(
     fichier_chemin: &String,
     hauteur: u32,
     largeur: u32,
     figures: &[Box<dyn Vectorisable>],
-) {
+) -> Result<(), Erreur> {
     let mut contenu_vec: Vec<String> = Vec::new();
 
     // Premiere ligne
@@ -71,7 +187,7 @@ pub fn cree_fichier_svg(
     let ligne: String = "</svg>".to_string();
     contenu_vec.push(ligne);
 
-    fichiers::ecrire_fichier_texte_lignes(fichier_chemin, &contenu_vec);
+    fichiers::ecrire_fichier_texte_lignes(fichier_chemin, &contenu_vec)
 }
 
 pub fn cree_fichier_svg_depuis_lignes(
@@ -79,14 +195,14 @@ pub fn cree_fichier_svg_depuis_lignes(
     hauteur: u32,
     largeur: u32,
     lignes: Vec<Ligne>,
-) {
+) -> Result<(), Erreur> {
     let mut figures: Vec<Box<dyn Vectorisable>> = Vec::new();
     for ligne in lignes {
         //lignes.push(Box::new(ligne {x1: x1, y1:y1, x2:x2, y2:y2, couleur: couleur.clone(), epaisseur:epaisseur}));
         let figure: Box<dyn Vectorisable> = Box::new(ligne);
         figures.push(figure);
     }
-    cree_fichier_svg(fichier_chemin, hauteur, largeur, &figures);
+    cree_fichier_svg(fichier_chemin, hauteur, largeur, &figures)
 }
 
 pub fn flocon_koch_recursif(lignes: &Vec<Ligne>, n_iter: u32) -> Vec<Ligne> {
@@ -246,69 +362,88 @@ fn calcule_fractale_interne(
     pixels
 }
 
-// Convertit une chaine hexadecimale
-// avec des octets séparés par des espaces
-fn convertit_str_to_vec_u8(chaine_hexa: &str) -> Vec<u8> {
-    let mut octets_retour: Vec<u8> = Vec::new();
-    let octet_hexa_vec = chaine_hexa.split_whitespace().collect::<Vec<_>>();
-    for octet_hexa in octet_hexa_vec {
-        let v = u8::from_str_radix(octet_hexa, 16)
-            .expect("Erreur avec u8::from_str_radix(): n'a pu faire la conversion.");
-        octets_retour.push(v);
-    }
+// Ecrit une image matricielle au format BMP (24 bits par pixel, non compressé; pas de dépendance à des lib externes)
+// 'pixels' contient les couleurs (rouge, vert, bleu) ligne par ligne, en partant du haut de l'image
+// Voir: https://fr.wikipedia.org/wiki/Windows_bitmap
+pub fn ecrit_bmp(
+    fichier_chemin: &String,
+    largeur: u32,
+    hauteur: u32,
+    pixels: &[(u8, u8, u8)],
+) -> Result<(), Erreur> {
+    assert_eq!(
+        pixels.len(),
+        (largeur * hauteur) as usize,
+        "Erreur: Le nombre de pixels ne correspond pas à la taille de l'image"
+    );
 
-    octets_retour
-}
+    // Chaque ligne de pixels est complétée par des zéros, jusqu'à un multiple de 4 octets
+    let taille_ligne: u32 = (3 * largeur).div_ceil(4) * 4;
+    let taille_pixels: u32 = taille_ligne * hauteur;
+    let taille_entetes: u32 = 14 + 40;
+    // Résolution: 3543 pixels par mètre (90 dpi)
+    let resolution: u32 = 3543;
 
-// Calcule l'image d'une fractale, et l'écrit dans un fichier bmp
-// (Format d'image matriciel non compressé; pas de dépendance à des lib externes)
-pub fn calcule_fractale_et_ecrit_bmp(x_fractale: f64, y_fractale: f64, f_fractale_bmp: &String) {
     let mut bmp_octets: Vec<u8> = Vec::new();
+    // Entete du fichier
+    bmp_octets.extend_from_slice(b"BM");
+    bmp_octets.extend_from_slice(&(taille_entetes + taille_pixels).to_le_bytes());
+    bmp_octets.extend_from_slice(&0u32.to_le_bytes());
+    bmp_octets.extend_from_slice(&taille_entetes.to_le_bytes());
+    // Entete de l'image (BITMAPINFOHEADER)
+    bmp_octets.extend_from_slice(&40u32.to_le_bytes());
+    bmp_octets.extend_from_slice(&largeur.to_le_bytes());
+    bmp_octets.extend_from_slice(&hauteur.to_le_bytes());
+    bmp_octets.extend_from_slice(&1u16.to_le_bytes()); // Nombre de plans
+    bmp_octets.extend_from_slice(&24u16.to_le_bytes()); // Bits par pixel
+    bmp_octets.extend_from_slice(&0u32.to_le_bytes()); // Pas de compression
+    bmp_octets.extend_from_slice(&taille_pixels.to_le_bytes());
+    bmp_octets.extend_from_slice(&resolution.to_le_bytes());
+    bmp_octets.extend_from_slice(&resolution.to_le_bytes());
+    bmp_octets.extend_from_slice(&0u32.to_le_bytes()); // Palette
+    bmp_octets.extend_from_slice(&0u32.to_le_bytes()); // Couleurs importantes
+
+    // Les lignes sont stockées de bas en haut, et chaque pixel en (bleu, vert, rouge)
+    for ligne in pixels.chunks(largeur as usize).rev() {
+        for (rouge, vert, bleu) in ligne {
+            bmp_octets.extend_from_slice(&[*bleu, *vert, *rouge]);
+        }
+        bmp_octets.resize(bmp_octets.len() + (taille_ligne - 3 * largeur) as usize, 0);
+    }
 
+    fichiers::ecrire_fichier_binaire(fichier_chemin, &bmp_octets)
+}
+
+// Calcule l'image d'une fractale, et l'écrit dans un fichier bmp (640x480)
+pub fn calcule_fractale_et_ecrit_bmp(
+    x_fractale: f64,
+    y_fractale: f64,
+    f_fractale_bmp: &String,
+) -> Result<(), Erreur> {
     let x_min = -1.;
     let x_max = 1.;
     let y_min = -1.;
     let y_max = 1.;
 
     let max_n_iter: usize = 50;
-    //let epaisseur_svg = 1;
 
-    // Entete BMP (640x480) - Résolution harcodée (Fixée dans l'entete BMP)
-    // TODO: Trouver un moyen d'écrire une image matricielle, sans lib externe,
-    //       et en pouvant choisir ses paramètres (résolution) etc.
     let hauteur = 640;
     let largeur = 480;
-    let debut_bmp = "42 4d 36 10 0e 00 00 00 00 00 36 00 00 00 28 00";
-    let debut_bmp2 = "00 00 80 02 00 00 e0 01 00 00 01 00 18 00 00 00";
-    let debut_bmp3 = "00 00 00 10 0e 00 d7 0d 00 00 d7 0d 00 00 00 00";
-    let debut_bmp4 = "00 00 00 00 00 00";
-    bmp_octets.append(&mut convertit_str_to_vec_u8(debut_bmp));
-    bmp_octets.append(&mut convertit_str_to_vec_u8(debut_bmp2));
-    bmp_octets.append(&mut convertit_str_to_vec_u8(debut_bmp3));
-    bmp_octets.append(&mut convertit_str_to_vec_u8(debut_bmp4));
-
     let img_pixels: Vec<f64> = calcule_fractale_interne(
         x_min, x_max, largeur, y_min, y_max, hauteur, max_n_iter, x_fractale, y_fractale,
     );
 
-    for x_index in (0u32..largeur) {
+    // L'image BMP fait 640 pixels de large et 480 de haut: elle est transposée
+    // par rapport à la grille calculée (et ecrit_bmp attend les lignes en partant du haut)
+    let mut pixels: Vec<(u8, u8, u8)> = Vec::new();
+    for x_index in (0u32..largeur).rev() {
         for y_index in (0u32..hauteur) {
             let pixel_index = (y_index * largeur + x_index) as usize;
             let pixel: f64 = img_pixels[pixel_index];
-            //println!("{}", pixel);
             let intensite: u8 = (255. * pixel) as u8;
-            //println!("{}", intensite);
-            bmp_octets.push(intensite);
-            bmp_octets.push(0);
-            bmp_octets.push(0);
-
-            //let mut couleur_str: String = String::new();
-            //write!(couleur_str, "rgb(0,0,{})", intensite).expect("Erreur dans write");
-
-            //rgb(0,255,0)
-            //lignes.push(Ligne {x1: x_index, y1:y_index, x2:x_index, y2:y_index, couleur: couleur_str, epaisseur:epaisseur_svg});
+            pixels.push((0, 0, intensite));
         }
     }
 
-    fichiers::ecrire_fichier_binaire(f_fractale_bmp, &bmp_octets);
+    ecrit_bmp(f_fractale_bmp, hauteur, largeur, &pixels)
 }
@@ -0,0 +1,308 @@
+// Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
+#![allow(unused_parens)]
+
+// Génération de labyrinthes parfaits (un seul chemin entre 2 cases quelconques)
+// au même format ASCII que les plans dessinés à la main (voir divers/labyrinthes/labyrinthe1.txt):
+//   .-.-.-.
+//   |@  | |
+//   : :-: :
+//   |     |
+//   :-:-:$:
+// Un labyrinthe de 'hauteur' x 'largeur' cases donne un plan de (2.hauteur + 1) lignes
+// de (2.largeur + 1) caractères: les cases sont aux positions impaires, les murs entre elles.
+// Le départ '@' est dans la case en haut à gauche, l'arrivée '$' est une ouverture
+// dans le mur du bas, sous la case en bas à droite.
+// Voir: https://en.wikipedia.org/wiki/Maze_generation_algorithm
+
+use crate::arbres_couvrants::EnsemblesDisjoints;
+use crate::graphe_pondere::GraphePondere;
+use crate::graphes::Position;
+use crate::probabilites::RngMinstd;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AlgoGeneration {
+    // Parcours en profondeur aléatoire (recursive backtracker): longs couloirs, peu d'impasses
+    RetourSurTrace,
+    // Kruskal aléatoire: on ouvre les murs dans un ordre aléatoire, s'ils séparent 2 zones non reliées
+    Kruskal,
+    // Prim aléatoire: le labyrinthe grandit depuis une case, par un mur aléatoire de sa frontière
+    Prim,
+    // Wilson: marches aléatoires à boucles effacées; tire uniformément parmi tous les labyrinthes parfaits
+    Wilson,
+}
+
+// Grille de cases, dont on ouvre progressivement les murs
+struct Grille {
+    hauteur: usize,
+    largeur: usize,
+    // Plan en cours de construction: plan[ligne][colonne]
+    plan: Vec<Vec<char>>,
+}
+
+impl Grille {
+    // Grille dont toutes les cases sont fermées
+    fn new(hauteur: usize, largeur: usize) -> Self {
+        let mut plan: Vec<Vec<char>> = Vec::new();
+        for index_ligne in (0..(2 * hauteur + 1)) {
+            let mut ligne: Vec<char> = Vec::new();
+            for index_colonne in (0..(2 * largeur + 1)) {
+                let caractere = match (index_ligne % 2, index_colonne % 2) {
+                    (0, 0) if (index_ligne == 0) => '.',
+                    (0, 0) => ':',
+                    (0, _) => '-',
+                    (_, 0) => '|',
+                    _ => ' ',
+                };
+                ligne.push(caractere);
+            }
+            plan.push(ligne);
+        }
+        Grille {
+            hauteur,
+            largeur,
+            plan,
+        }
+    }
+
+    fn nb_cases(&self) -> usize {
+        self.hauteur * self.largeur
+    }
+
+    // Numérotation des cases: case = ligne * largeur + colonne
+    fn voisins(&self, case: usize) -> Vec<usize> {
+        let (ligne, colonne) = (case / self.largeur, case % self.largeur);
+        let mut voisins: Vec<usize> = Vec::new();
+        if (ligne > 0) {
+            voisins.push(case - self.largeur);
+        }
+        if (ligne + 1 < self.hauteur) {
+            voisins.push(case + self.largeur);
+        }
+        if (colonne > 0) {
+            voisins.push(case - 1);
+        }
+        if (colonne + 1 < self.largeur) {
+            voisins.push(case + 1);
+        }
+        voisins
+    }
+
+    // Ouvre le mur entre 2 cases voisines
+    fn ouvre_mur(&mut self, case1: usize, case2: usize) {
+        let (ligne1, colonne1) = (case1 / self.largeur, case1 % self.largeur);
+        let (ligne2, colonne2) = (case2 / self.largeur, case2 % self.largeur);
+        self.plan[ligne1 + ligne2 + 1][colonne1 + colonne2 + 1] = ' ';
+    }
+
+    // Place le départ et l'arrivée, et convertit le plan en lignes de texte
+    fn vers_plan(mut self) -> Vec<String> {
+        self.plan[1][1] = '@';
+        self.plan[2 * self.hauteur][2 * self.largeur - 1] = '$';
+        self.plan
+            .iter()
+            .map(|ligne| ligne.iter().collect::<String>())
+            .collect()
+    }
+}
+
+// Renvoie un indice aléatoire dans [0, n[
+fn indice_aleatoire(rng: &mut RngMinstd, n: usize) -> usize {
+    rng.gen_range(0..n) as usize
+}
+
+fn genere_retour_sur_trace(grille: &mut Grille, rng: &mut RngMinstd) {
+    let mut visitees: Vec<bool> = vec![false; grille.nb_cases()];
+    let case_depart = indice_aleatoire(rng, grille.nb_cases());
+    visitees[case_depart] = true;
+    // Pile explicite plutôt que la récursion, pour les grands labyrinthes
+    let mut pile: Vec<usize> = vec![case_depart];
+    while let Some(&case_cour) = pile.last() {
+        let voisins_libres: Vec<usize> = grille
+            .voisins(case_cour)
+            .into_iter()
+            .filter(|voisin| !visitees[*voisin])
+            .collect();
+        if (voisins_libres.is_empty()) {
+            // Impasse: retour sur nos pas
+            pile.pop();
+            continue;
+        }
+        let voisin = voisins_libres[indice_aleatoire(rng, voisins_libres.len())];
+        grille.ouvre_mur(case_cour, voisin);
+        visitees[voisin] = true;
+        pile.push(voisin);
+    }
+}
+
+fn genere_kruskal(grille: &mut Grille, rng: &mut RngMinstd) {
+    // Tous les murs intérieurs, mélangés (Fisher-Yates)
+    let mut murs: Vec<(usize, usize)> = Vec::new();
+    for case in (0..grille.nb_cases()) {
+        for voisin in grille.voisins(case) {
+            if (case < voisin) {
+                murs.push((case, voisin));
+            }
+        }
+    }
+    for index in (1..murs.len()).rev() {
+        let index_echange = indice_aleatoire(rng, index + 1);
+        murs.swap(index, index_echange);
+    }
+
+    let mut ensembles = EnsemblesDisjoints::new(grille.nb_cases());
+    for (case1, case2) in murs {
+        if (ensembles.unit(case1, case2)) {
+            grille.ouvre_mur(case1, case2);
+        }
+    }
+}
+
+fn genere_prim(grille: &mut Grille, rng: &mut RngMinstd) {
+    let mut dans_labyrinthe: Vec<bool> = vec![false; grille.nb_cases()];
+    let case_depart = indice_aleatoire(rng, grille.nb_cases());
+    dans_labyrinthe[case_depart] = true;
+
+    // Frontière: murs entre une case du labyrinthe et une case voisine
+    let mut frontiere: Vec<(usize, usize)> = grille
+        .voisins(case_depart)
+        .into_iter()
+        .map(|voisin| (case_depart, voisin))
+        .collect();
+    while (!frontiere.is_empty()) {
+        let (case, voisin) = frontiere.swap_remove(indice_aleatoire(rng, frontiere.len()));
+        if (dans_labyrinthe[voisin]) {
+            continue;
+        }
+        grille.ouvre_mur(case, voisin);
+        dans_labyrinthe[voisin] = true;
+        for voisin_suivant in grille.voisins(voisin) {
+            if (!dans_labyrinthe[voisin_suivant]) {
+                frontiere.push((voisin, voisin_suivant));
+            }
+        }
+    }
+}
+
+fn genere_wilson(grille: &mut Grille, rng: &mut RngMinstd) {
+    let mut dans_labyrinthe: Vec<bool> = vec![false; grille.nb_cases()];
+    dans_labyrinthe[indice_aleatoire(rng, grille.nb_cases())] = true;
+
+    // Direction de sortie de chaque case lors de la marche aléatoire en cours:
+    // la réécrire à chaque passage efface les boucles
+    let mut case_suivante: Vec<usize> = vec![0; grille.nb_cases()];
+    for case_depart in (0..grille.nb_cases()) {
+        if (dans_labyrinthe[case_depart]) {
+            continue;
+        }
+
+        // Marche aléatoire jusqu'à atteindre le labyrinthe
+        let mut case_cour = case_depart;
+        while (!dans_labyrinthe[case_cour]) {
+            let voisins = grille.voisins(case_cour);
+            let voisin = voisins[indice_aleatoire(rng, voisins.len())];
+            case_suivante[case_cour] = voisin;
+            case_cour = voisin;
+        }
+
+        // Ajout au labyrinthe du chemin sans boucle
+        let mut case_cour = case_depart;
+        while (!dans_labyrinthe[case_cour]) {
+            dans_labyrinthe[case_cour] = true;
+            grille.ouvre_mur(case_cour, case_suivante[case_cour]);
+            case_cour = case_suivante[case_cour];
+        }
+    }
+}
+
+// Génère le plan d'un labyrinthe parfait de 'hauteur' x 'largeur' cases, utilisable par Labyrinthe::new
+// Le même 'seed' (non nul) donne toujours le même labyrinthe
+pub fn genere_labyrinthe(
+    hauteur: usize,
+    largeur: usize,
+    algo: AlgoGeneration,
+    seed: u32,
+) -> Vec<String> {
+    assert!(
+        hauteur >= 1 && largeur >= 1,
+        "Erreur: Le labyrinthe doit avoir au moins une case"
+    );
+    let mut rng: RngMinstd = RngMinstd::new(seed);
+    let mut grille = Grille::new(hauteur, largeur);
+    match (algo) {
+        AlgoGeneration::RetourSurTrace => genere_retour_sur_trace(&mut grille, &mut rng),
+        AlgoGeneration::Kruskal => genere_kruskal(&mut grille, &mut rng),
+        AlgoGeneration::Prim => genere_prim(&mut grille, &mut rng),
+        AlgoGeneration::Wilson => genere_wilson(&mut grille, &mut rng),
+    }
+    grille.vers_plan()
+}
+
+// Graphe de la grille de 'hauteur' x 'largeur' cases (non orienté): chaque case est reliée à ses voisines
+// (haut, bas, gauche, droite), avec des poids aléatoires dans [0, 1000[.
+// Un arbre couvrant de poids minimal de ce graphe (arbres_couvrants::kruskal ou prim)
+// est un labyrinthe parfait aléatoire: voir plan_depuis_arbre_couvrant
+pub fn graphe_grille_aleatoire(
+    hauteur: usize,
+    largeur: usize,
+    seed: u32,
+) -> GraphePondere<Position, u32> {
+    let mut rng: RngMinstd = RngMinstd::new(seed);
+    let mut graphe: GraphePondere<Position, u32> = GraphePondere::new(false);
+    for ligne in (0..hauteur) {
+        for colonne in (0..largeur) {
+            let case = Position::new(ligne, colonne);
+            graphe.ajoute_sommet(case);
+            if (ligne > 0) {
+                graphe.ajoute_arete(
+                    Position::new(ligne - 1, colonne),
+                    case,
+                    rng.gen_range(0..1000),
+                );
+            }
+            if (colonne > 0) {
+                graphe.ajoute_arete(
+                    Position::new(ligne, colonne - 1),
+                    case,
+                    rng.gen_range(0..1000),
+                );
+            }
+        }
+    }
+    graphe
+}
+
+// Plan du labyrinthe de 'hauteur' x 'largeur' cases dont les passages sont les arêtes d'un arbre couvrant
+// de la grille (même format que genere_labyrinthe; les positions sont celles des cases, pas du plan)
+// Chaque arête doit relier 2 cases voisines de la grille.
+pub fn plan_depuis_arbre_couvrant<A>(
+    hauteur: usize,
+    largeur: usize,
+    aretes: &[(Position, Position, A)],
+) -> Vec<String> {
+    assert!(
+        hauteur >= 1 && largeur >= 1,
+        "Erreur: Le labyrinthe doit avoir au moins une case"
+    );
+    let mut grille = Grille::new(hauteur, largeur);
+    for (case1, case2, _poids) in aretes {
+        assert!(
+            case1.ligne < hauteur
+                && case2.ligne < hauteur
+                && case1.colonne < largeur
+                && case2.colonne < largeur,
+            "Erreur: Case en dehors de la grille"
+        );
+        assert_eq!(
+            case1.ligne.abs_diff(case2.ligne) + case1.colonne.abs_diff(case2.colonne),
+            1,
+            "Erreur: Les cases {} et {} ne sont pas voisines",
+            case1,
+            case2
+        );
+        grille.ouvre_mur(
+            case1.ligne * largeur + case1.colonne,
+            case2.ligne * largeur + case2.colonne,
+        );
+    }
+    grille.vers_plan()
+}
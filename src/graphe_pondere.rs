@@ -0,0 +1,317 @@
+// Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
+#![allow(unused_parens)]
+
+// Graphe pondéré général, stocké sous forme de listes d'adjacence
+// Généricité (comme pour le trait Voisins): S = type des Sommets, et A = type des poids des arêtes
+// Le graphe peut être orienté (arcs) ou non orienté (chaque arête est parcourable dans les 2 sens).
+// Il implémente Voisins, donc resoud_dijstra, resoud_a_etoile etc. s'appliquent directement.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::ops::Add;
+use std::str::FromStr;
+
+use crate::erreurs::Erreur;
+use crate::fichiers;
+use crate::graphes::{Sommets, Voisins};
+
+#[derive(Clone, Debug)]
+pub struct GraphePondere<S, A> {
+    oriente: bool,
+    // Sommets, dans l'ordre d'insertion; un sommet est désigné en interne par son indice
+    sommets: Vec<S>,
+    indices: HashMap<S, usize>,
+    // Arêtes telles qu'ajoutées (une seule fois, même si le graphe n'est pas orienté)
+    aretes: Vec<(usize, usize, A)>,
+    // Pour chaque sommet, la liste de ses voisins (indice) avec le poids de l'arête
+    adjacence: Vec<Vec<(usize, A)>>,
+}
+
+impl<S, A> GraphePondere<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: Clone,
+{
+    pub fn new(oriente: bool) -> Self {
+        GraphePondere {
+            oriente,
+            sommets: Vec::new(),
+            indices: HashMap::new(),
+            aretes: Vec::new(),
+            adjacence: Vec::new(),
+        }
+    }
+
+    pub fn est_oriente(&self) -> bool {
+        self.oriente
+    }
+
+    pub fn nb_sommets(&self) -> usize {
+        self.sommets.len()
+    }
+
+    pub fn nb_aretes(&self) -> usize {
+        self.aretes.len()
+    }
+
+    // Sommets, dans l'ordre d'insertion
+    pub fn sommets(&self) -> &[S] {
+        &self.sommets
+    }
+
+    pub fn contient_sommet(&self, sommet: &S) -> bool {
+        self.indices.contains_key(sommet)
+    }
+
+    // Ajoute un sommet (sans effet s'il existe déjà), et renvoie son indice
+    pub fn ajoute_sommet(&mut self, sommet: S) -> usize {
+        if let Some(indice) = self.indices.get(&sommet) {
+            return *indice;
+        }
+        let indice = self.sommets.len();
+        self.indices.insert(sommet.clone(), indice);
+        self.sommets.push(sommet);
+        self.adjacence.push(Vec::new());
+        indice
+    }
+
+    // Ajoute une arête (un arc de 'source' vers 'destination' si le graphe est orienté)
+    // Les sommets absents sont ajoutés au graphe
+    pub fn ajoute_arete(&mut self, source: S, destination: S, poids: A) {
+        let i_source = self.ajoute_sommet(source);
+        let i_destination = self.ajoute_sommet(destination);
+        self.adjacence[i_source].push((i_destination, poids.clone()));
+        if (!self.oriente && i_source != i_destination) {
+            self.adjacence[i_destination].push((i_source, poids.clone()));
+        }
+        self.aretes.push((i_source, i_destination, poids));
+    }
+
+    // Liste des arêtes (source, destination, poids), dans l'ordre d'ajout
+    // Une arête non orientée n'apparait qu'une fois
+    pub fn aretes(&self) -> Vec<(S, S, A)> {
+        self.aretes
+            .iter()
+            .map(|(i_source, i_destination, poids)| {
+                (
+                    self.sommets[*i_source].clone(),
+                    self.sommets[*i_destination].clone(),
+                    poids.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<S, A> Voisins<S, A> for GraphePondere<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + Clone,
+{
+    fn liste_voisins_et_distances(&self, sommet: &S) -> Vec<(S, A)> {
+        match (self.indices.get(sommet)) {
+            Some(indice) => self.adjacence[*indice]
+                .iter()
+                .map(|(i_voisin, poids)| (self.sommets[*i_voisin].clone(), poids.clone()))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+impl<S, A> Sommets<S> for GraphePondere<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: Clone,
+{
+    fn liste_sommets(&self) -> Vec<S> {
+        self.sommets.clone()
+    }
+}
+
+// Conversion d'un champ d'une ligne de fichier, avec une erreur indiquant la ligne fautive
+fn lit_champ<T: FromStr>(
+    champ: &str,
+    description: &str,
+    chemin: &str,
+    num_ligne: usize,
+) -> Result<T, Erreur> {
+    champ.parse::<T>().map_err(|_| {
+        Erreur::FormatInvalide(
+            chemin.to_string(),
+            num_ligne,
+            format!("{} invalide: '{}'", description, champ),
+        )
+    })
+}
+
+// Format 'liste d'arêtes' (texte):
+//   # Commentaire
+//   source destination poids
+//   sommet_isolé
+// Une arête par ligne (champs séparés par des espaces), les lignes vides et commentaires sont ignorés.
+// Une ligne avec un seul champ déclare un sommet (utile pour les sommets isolés).
+impl<S, A> GraphePondere<S, A>
+where
+    S: Eq + Hash + Clone + FromStr + Display,
+    A: Clone + FromStr + Display,
+{
+    pub fn charge_liste_aretes(chemin: &String, oriente: bool) -> Result<Self, Erreur> {
+        let lignes: Vec<String> = fichiers::lire_fichier_texte_lignes(chemin, None)?;
+        let mut graphe: GraphePondere<S, A> = GraphePondere::new(oriente);
+
+        for (index, ligne) in lignes.iter().enumerate() {
+            let num_ligne = index + 1;
+            let ligne = ligne.trim();
+            if (ligne.is_empty() || ligne.starts_with('#')) {
+                continue;
+            }
+            let champs: Vec<&str> = ligne.split_whitespace().collect();
+            match (champs.len()) {
+                1 => {
+                    graphe.ajoute_sommet(lit_champ(champs[0], "Sommet", chemin, num_ligne)?);
+                }
+                3 => {
+                    let source: S = lit_champ(champs[0], "Sommet", chemin, num_ligne)?;
+                    let destination: S = lit_champ(champs[1], "Sommet", chemin, num_ligne)?;
+                    let poids: A = lit_champ(champs[2], "Poids", chemin, num_ligne)?;
+                    graphe.ajoute_arete(source, destination, poids);
+                }
+                _ => {
+                    return Err(Erreur::FormatInvalide(
+                        chemin.clone(),
+                        num_ligne,
+                        "Attendu: 'source destination poids' ou 'sommet'".to_string(),
+                    ))
+                }
+            }
+        }
+
+        Ok(graphe)
+    }
+
+    pub fn sauve_liste_aretes(&self, chemin: &String) -> Result<(), Erreur> {
+        let mut lignes: Vec<String> = Vec::new();
+        let type_graphe = if (self.oriente) {
+            "orienté"
+        } else {
+            "non orienté"
+        };
+        lignes.push(format!(
+            "# Graphe {}: {} sommets, {} arêtes",
+            type_graphe,
+            self.nb_sommets(),
+            self.nb_aretes()
+        ));
+        lignes.push("# source destination poids".to_string());
+
+        for (source, destination, poids) in self.aretes() {
+            lignes.push(format!("{} {} {}", source, destination, poids));
+        }
+
+        // Sommets isolés, pour qu'ils soient conservés au rechargement
+        let mut est_isole: Vec<bool> = vec![true; self.nb_sommets()];
+        for (i_source, i_destination, _poids) in &self.aretes {
+            est_isole[*i_source] = false;
+            est_isole[*i_destination] = false;
+        }
+        for (indice, sommet) in self.sommets.iter().enumerate() {
+            if (est_isole[indice]) {
+                lignes.push(format!("{}", sommet));
+            }
+        }
+
+        fichiers::ecrire_fichier_texte_lignes(chemin, &lignes)
+    }
+}
+
+// Format DIMACS (9ème challenge DIMACS, plus courts chemins; ex: réseaux routiers .gr)
+//   c Commentaire
+//   p sp <nb_sommets> <nb_arcs>
+//   a <source> <destination> <poids>
+// Les sommets sont numérotés de 1 à nb_sommets.
+// Voir: http://www.diag.uniroma1.it/challenge9/format.shtml
+impl<A> GraphePondere<usize, A>
+where
+    A: Clone + FromStr,
+{
+    pub fn charge_dimacs(chemin: &String, oriente: bool) -> Result<Self, Erreur> {
+        let lignes: Vec<String> = fichiers::lire_fichier_texte_lignes(chemin, None)?;
+        let mut graphe: GraphePondere<usize, A> = GraphePondere::new(oriente);
+        let mut nb_sommets_opt: Option<usize> = None;
+
+        for (index, ligne) in lignes.iter().enumerate() {
+            let num_ligne = index + 1;
+            let champs: Vec<&str> = ligne.split_whitespace().collect();
+            let erreur_format = |message: &str| {
+                Erreur::FormatInvalide(chemin.clone(), num_ligne, message.to_string())
+            };
+            match (champs.first()) {
+                None | Some(&"c") => continue,
+                Some(&"p") => {
+                    if (champs.len() != 4 || nb_sommets_opt.is_some()) {
+                        return Err(erreur_format("Attendu une seule ligne 'p sp n m'"));
+                    }
+                    if (champs[1] != "sp") {
+                        return Err(erreur_format(
+                            "Format de problème non supporté (attendu: 'sp')",
+                        ));
+                    }
+                    let nb_sommets: usize =
+                        lit_champ(champs[2], "Nombre de sommets", chemin, num_ligne)?;
+                    for sommet in (1..=nb_sommets) {
+                        graphe.ajoute_sommet(sommet);
+                    }
+                    nb_sommets_opt = Some(nb_sommets);
+                }
+                Some(&"a") => {
+                    let nb_sommets =
+                        nb_sommets_opt.ok_or_else(|| erreur_format("Arc avant la ligne 'p'"))?;
+                    if (champs.len() != 4) {
+                        return Err(erreur_format("Attendu: 'a source destination poids'"));
+                    }
+                    let source: usize = lit_champ(champs[1], "Sommet", chemin, num_ligne)?;
+                    let destination: usize = lit_champ(champs[2], "Sommet", chemin, num_ligne)?;
+                    let poids: A = lit_champ(champs[3], "Poids", chemin, num_ligne)?;
+                    if (!(1..=nb_sommets).contains(&source)
+                        || !(1..=nb_sommets).contains(&destination))
+                    {
+                        return Err(erreur_format("Sommet hors de [1, nb_sommets]"));
+                    }
+                    graphe.ajoute_arete(source, destination, poids);
+                }
+                Some(_) => return Err(erreur_format("Ligne inconnue (attendu: c, p ou a)")),
+            }
+        }
+
+        Ok(graphe)
+    }
+}
+
+impl<S, A> GraphePondere<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: Clone + Display,
+{
+    // Les sommets sont renumérotés de 1 à n, dans l'ordre d'insertion (leurs noms ne sont pas conservés)
+    pub fn sauve_dimacs(&self, chemin: &String) -> Result<(), Erreur> {
+        let mut lignes: Vec<String> = Vec::new();
+        let type_graphe = if (self.oriente) {
+            "orienté"
+        } else {
+            "non orienté"
+        };
+        lignes.push(format!("c Graphe {}", type_graphe));
+        lignes.push(format!("p sp {} {}", self.nb_sommets(), self.nb_aretes()));
+        for (i_source, i_destination, poids) in &self.aretes {
+            lignes.push(format!(
+                "a {} {} {}",
+                i_source + 1,
+                i_destination + 1,
+                poids
+            ));
+        }
+        fichiers::ecrire_fichier_texte_lignes(chemin, &lignes)
+    }
+}
@@ -3,14 +3,19 @@
 // Ne pas faire de warning si des fonctions ne sont pas appelées
 #![warn(dead_code)]
 
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::hash::Hash;
 
 //use std::intrinsics::discriminant_value;
 use std::fmt::Debug;
 use std::ops::Add;
 
+use crate::erreurs::Erreur;
 use crate::fichiers;
+use crate::fractales::{self, Rectangle, Vectorisable};
+use crate::parcours_graphes::resoud_largeur_avec_couts;
+use crate::probabilites::RngMinstd;
 
 // Définition d'un trait pour les sommets:
 // A partir d'un sommet, on doit être capable de lister ses voisins + avoir les distances respectives
@@ -23,44 +28,116 @@ where
     fn liste_voisins_et_distances(&self, sommet: &S) -> Vec<(S, A)>;
 }
 
-fn trouve_clef_min_val_dans_hashmap<S, A>(c: &HashMap<S, A>) -> (S, A)
-where
-    S: Eq + Hash + Clone,
-    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug,
-{
-    let mut s_min_opt: Option<&S> = None;
-    let mut a_min_opt: Option<&A> = None;
-    for (s, a) in c.iter() {
-        if (a_min_opt.is_some()) {
-            if (a < a_min_opt.unwrap()) {
-                s_min_opt = Some(s);
-                a_min_opt = Some(a);
-            }
-        } else {
-            s_min_opt = Some(s);
-            a_min_opt = Some(a);
+// Enumération de tous les sommets d'un graphe
+// (nécessaire aux algorithmes qui ne se limitent pas aux sommets atteints, ex: Bellman-Ford)
+pub trait Sommets<S> {
+    fn liste_sommets(&self) -> Vec<S>;
+}
+
+// Erreurs des algorithmes de plus court chemin
+#[derive(Clone, Debug, PartialEq)]
+pub enum ErreurGraphe<S, A> {
+    // Arête de poids négatif rencontrée par Dijkstra (ou A*, glouton): source, destination, poids
+    PoidsNegatif(S, S, A),
+    // Cycle de poids total négatif, accessible depuis les sommets initiaux (Bellman-Ford)
+    // Sommets du cycle dans l'ordre de parcours, le premier sommet étant répété à la fin
+    CycleNegatif(Vec<S>),
+    // Cycle dans un graphe orienté qui doit être acyclique (tri topologique), même format
+    Cycle(Vec<S>),
+}
+
+impl<S: Debug, A: Debug> std::fmt::Display for ErreurGraphe<S, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self) {
+            ErreurGraphe::PoidsNegatif(source, destination, poids) => write!(
+                f,
+                "Poids négatif {:?} sur l'arête {:?} -> {:?}: utiliser Bellman-Ford",
+                poids, source, destination
+            ),
+            ErreurGraphe::CycleNegatif(cycle) => write!(f, "Cycle de poids négatif: {:?}", cycle),
+            ErreurGraphe::Cycle(cycle) => write!(f, "Cycle dans le graphe: {:?}", cycle),
         }
     }
+}
 
-    (s_min_opt.unwrap().clone(), a_min_opt.unwrap().clone())
+impl<S: Debug, A: Debug> std::error::Error for ErreurGraphe<S, A> {}
+
+// Résultat de resoud_dijstra, resoud_a_etoile et resoud_glouton:
+// distances des sommets parcourus, sommets précédents, et sommet final atteint (le cas échéant)
+pub type ResultatRecherche<S, A> =
+    Result<(HashMap<S, A>, HashMap<S, S>, Option<S>), ErreurGraphe<S, A>>;
+
+// Résultat de resoud_dijstra_toutes_cibles et resoud_dijstra_k_plus_proches:
+// distances des sommets parcourus, sommets précédents,
+// et sommets finaux atteints avec leur distance, par distance croissante
+pub type ResultatCibles<S, A> =
+    Result<(HashMap<S, A>, HashMap<S, S>, Vec<(S, A)>), ErreurGraphe<S, A>>;
+
+// Résultat de resoud_bellman_ford et resoud_dijstra_arbre_complet:
+// distances des sommets accessibles, et sommets précédents
+pub type ResultatPlusCourtsChemins<S, A> =
+    Result<(HashMap<S, A>, HashMap<S, S>), ErreurGraphe<S, A>>;
+
+// Elément de la file de priorité: un sommet, avec sa distance provisoire aux sommets initiaux
+// et sa priorité (= la distance pour Dijkstra, distance + heuristique pour A*)
+// std::collections::BinaryHeap est un tas max => l'ordre est inversé pour extraire la priorité minimale
+// Les distances A sont seulement PartialOrd (ex: flottants): deux priorités incomparables
+// (NaN) sont considérées égales.
+pub(crate) struct ElementFile<S, A> {
+    pub(crate) priorite: A,
+    pub(crate) dist: A,
+    pub(crate) sommet: S,
 }
 
-// Implémentation de l'algorithme de Dijkstra
-// https://fr.wikipedia.org/wiki/Algorithme_de_Dijkstra
-// Entrées: Sommets de départ, et terminaux
+impl<S, A: PartialOrd> PartialEq for ElementFile<S, A> {
+    fn eq(&self, autre: &Self) -> bool {
+        self.cmp(autre) == Ordering::Equal
+    }
+}
+
+impl<S, A: PartialOrd> Eq for ElementFile<S, A> {}
+
+impl<S, A: PartialOrd> PartialOrd for ElementFile<S, A> {
+    fn partial_cmp(&self, autre: &Self) -> Option<Ordering> {
+        Some(self.cmp(autre))
+    }
+}
+
+impl<S, A: PartialOrd> Ord for ElementFile<S, A> {
+    fn cmp(&self, autre: &Self) -> Ordering {
+        // Ordre inversé: la plus petite priorité est la plus prioritaire
+        autre
+            .priorite
+            .partial_cmp(&self.priorite)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+// Parcours 'meilleur d'abord', commun à Dijkstra, A* et la recherche gloutonne:
+// on extrait à chaque itération le sommet de la liste courante de plus petite priorité.
+// priorite(dist, sommet) donne la priorité d'un sommet, à partir de sa distance aux sommets initiaux
 // L'algorithme s'arrête dés que l'une des 2 conditions est remplie:
-// - Soit on a trouvé un chemin d'un sommet de départ au sommet d'arrivée
+// - Soit on a atteint 'nb_cibles' sommets finaux distincts (None: pas d'arrêt anticipé)
 // - Soit la liste courante est vide (on a fini de parcourir la partie connexe du graphe, contenant les sommets initiaux)
-pub fn resoud_dijstra<G, S, A>(
+// Les sommets finaux atteints sont renvoyés avec leur distance, dans l'ordre où ils sont parcourus
+// La file de priorité est un tas binaire (BinaryHeap), avec suppression paresseuse:
+// quand la distance d'un sommet est améliorée, on l'insère à nouveau dans le tas,
+// et les anciennes entrées sont ignorées lors de leur extraction.
+// Un sommet parcouru n'est jamais revisité: l'algorithme est faux avec des poids négatifs,
+// d'où l'erreur ErreurGraphe::PoidsNegatif dès qu'une telle arête est rencontrée.
+fn parcours_meilleur_d_abord<G, S, A, F>(
     graphe: &G,
     s_init: Vec<S>,
     s_final: Vec<S>,
-) -> (HashMap<S, A>, HashMap<S, S>, Option<S>)
+    nb_cibles: Option<usize>,
+    priorite: F,
+) -> ResultatCibles<S, A>
 where
     G: Voisins<S, A>,
     S: Eq + Hash + Clone,
     A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
     <A as TryFrom<i8>>::Error: Debug,
+    F: Fn(&A, &S) -> A,
 {
     // Liste passée (HashMap) des sommets déjà parcourus avec leur distance aux sommets initiaux (Vide au départ)
     let mut p: HashMap<S, A> = HashMap::new();
@@ -68,6 +145,9 @@ where
     // Liste courante (HashMap) des sommets avec leur distance aux sommets initiaux
     let mut c: HashMap<S, A> = HashMap::new();
 
+    // File de priorité sur les sommets de la liste courante (peut contenir des entrées périmées)
+    let mut file: BinaryHeap<ElementFile<S, A>> = BinaryHeap::new();
+
     // Pour un sommet, donne le sommet précédent (en direction des sommets initiaux)
     let mut prec: HashMap<S, S> = HashMap::new();
 
@@ -76,14 +156,32 @@ where
     let dist_zero: A = A::try_from(0i8).expect("Distance nulle manquante pour le type A.");
     for sommet in s_init.iter() {
         let _ = &c.insert(sommet.clone(), dist_zero.clone());
+        file.push(ElementFile {
+            priorite: priorite(&dist_zero, sommet),
+            dist: dist_zero.clone(),
+            sommet: sommet.clone(),
+        });
     }
 
-    let mut sommet_final_opt: Option<S> = None;
+    let s_final: HashSet<S> = s_final.into_iter().collect();
+    let mut cibles: Vec<(S, A)> = Vec::new();
+    let objectif_atteint = |n_cibles_atteintes: usize| -> bool {
+        nb_cibles.is_some_and(|n_cibles| n_cibles_atteintes >= n_cibles)
+    };
+    if (objectif_atteint(0)) {
+        return Ok((p, prec, cibles));
+    }
 
     // Tant que la file n'est pas vide
-    while !c.is_empty() {
-        // Prendre le plus petit élément a de la file de priorité
-        let (sommet, dist) = trouve_clef_min_val_dans_hashmap(&c);
+    while let Some(ElementFile { dist, sommet, .. }) = file.pop() {
+        // Entrée périmée: le sommet a déjà été parcouru, ou sa distance a été améliorée depuis
+        if (p.contains_key(&sommet)) {
+            continue;
+        }
+        match (c.get(&sommet)) {
+            Some(dist_cour) if (&dist > dist_cour) => continue,
+            _ => {}
+        }
 
         // Retirer 'sommet' de la liste courante
         c.remove(&sommet);
@@ -92,41 +190,318 @@ where
         p.insert(sommet.clone(), dist.clone());
 
         if (s_final.contains(&sommet)) {
-            // On a trouvé un chemin optimal d'un sommet initial au final
-            sommet_final_opt = Some(sommet);
-            break;
+            // On a trouvé un chemin d'un sommet initial au final (optimal pour Dijkstra et A*)
+            cibles.push((sommet.clone(), dist.clone()));
+            if (objectif_atteint(cibles.len())) {
+                break;
+            }
         }
 
         //Itérer sur les 'voisins' de 'sommet':
         let voisins: Vec<(S, A)> = graphe.liste_voisins_et_distances(&sommet);
         for (voisin, voisin_dist) in voisins.iter() {
+            if (voisin_dist < &dist_zero) {
+                return Err(ErreurGraphe::PoidsNegatif(
+                    sommet,
+                    voisin.clone(),
+                    voisin_dist.clone(),
+                ));
+            }
+
             // Si le voisin a déjà été parcouru, on continue
             if p.contains_key(voisin) {
                 continue;
             }
 
             let dist_nouveau_possible = dist.clone() + voisin_dist.clone();
-            let dist_cour_opt = c.get(voisin);
-
-            // Cas ou le voisin est déjà dans la liste courante, avec une autre distance
-            if (dist_cour_opt.is_some()) {
-                let dist_cour = dist_cour_opt.unwrap();
-                if (&dist_nouveau_possible < dist_cour) {
-                    // Si on a amélioré la distance, on met à jour les structures
-                    //*C.get_mut(voisin).unwrap() = dist_nouveau_possible;
-                    //*prec.get_mut(voisin).unwrap() = sommet.clone();
-                    c.insert(voisin.clone(), dist_nouveau_possible);
-                    prec.insert(voisin.clone(), sommet.clone());
+
+            // Cas ou le voisin est déjà dans la liste courante, avec une distance au moins aussi bonne
+            if let Some(dist_cour) = c.get(voisin) {
+                if (&dist_nouveau_possible >= dist_cour) {
+                    continue;
                 }
-            } else {
-                // Si le voisin n'est pas dans la liste courante, on le rajoute
-                c.insert(voisin.clone(), dist_nouveau_possible);
+            }
+
+            // Si le voisin n'est pas dans la liste courante, on le rajoute
+            // et si on a amélioré sa distance, on met à jour les structures
+            // (l'ancienne entrée du tas sera ignorée à son extraction)
+            c.insert(voisin.clone(), dist_nouveau_possible.clone());
+            prec.insert(voisin.clone(), sommet.clone());
+            file.push(ElementFile {
+                priorite: priorite(&dist_nouveau_possible, voisin),
+                dist: dist_nouveau_possible,
+                sommet: voisin.clone(),
+            });
+        }
+    }
+
+    Ok((p, prec, cibles))
+}
+
+// Arrêt au premier sommet final atteint (resoud_dijstra, resoud_a_etoile, resoud_glouton)
+fn parcours_jusqu_a_la_premiere_cible<G, S, A, F>(
+    graphe: &G,
+    s_init: Vec<S>,
+    s_final: Vec<S>,
+    priorite: F,
+) -> ResultatRecherche<S, A>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
+    <A as TryFrom<i8>>::Error: Debug,
+    F: Fn(&A, &S) -> A,
+{
+    let (p, prec, cibles) = parcours_meilleur_d_abord(graphe, s_init, s_final, Some(1), priorite)?;
+    let sommet_final_opt: Option<S> = cibles.into_iter().next().map(|(sommet, _dist)| sommet);
+    Ok((p, prec, sommet_final_opt))
+}
+
+// Implémentation de l'algorithme de Dijkstra
+// https://fr.wikipedia.org/wiki/Algorithme_de_Dijkstra
+// Entrées: Sommets de départ, et terminaux
+// Sorties: Distances des sommets parcourus, sommets précédents, et sommet final atteint (le cas échéant)
+// Erreur si une arête de poids négatif est rencontrée (voir resoud_bellman_ford)
+// Complexité: O((V + E).log(V)), avec V le nombre de sommets et E le nombre d'arêtes parcourus
+pub fn resoud_dijstra<G, S, A>(
+    graphe: &G,
+    s_init: Vec<S>,
+    s_final: Vec<S>,
+) -> ResultatRecherche<S, A>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
+    <A as TryFrom<i8>>::Error: Debug,
+{
+    parcours_jusqu_a_la_premiere_cible(graphe, s_init, s_final, |dist: &A, _sommet: &S| {
+        dist.clone()
+    })
+}
+
+// Dijkstra sans arrêt anticipé: arbre complet des plus courts chemins depuis les sommets de départ
+// Sorties: Distances de tous les sommets accessibles (ex: carte des distances d'un labyrinthe),
+// et sommets précédents (voir reconstruit_chemin)
+pub fn resoud_dijstra_arbre_complet<G, S, A>(
+    graphe: &G,
+    s_init: Vec<S>,
+) -> ResultatPlusCourtsChemins<S, A>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
+    <A as TryFrom<i8>>::Error: Debug,
+{
+    let (p, prec, _cibles) =
+        parcours_meilleur_d_abord(graphe, s_init, Vec::new(), None, |dist: &A, _sommet: &S| {
+            dist.clone()
+        })?;
+    Ok((p, prec))
+}
+
+// Dijkstra jusqu'à ce que tous les sommets finaux soient atteints (ex: labyrinthe à plusieurs sorties)
+// Sorties: comme resoud_dijstra, mais avec la liste des sommets finaux atteints et leur distance,
+// par distance croissante; les sommets finaux inaccessibles en sont absents.
+pub fn resoud_dijstra_toutes_cibles<G, S, A>(
+    graphe: &G,
+    s_init: Vec<S>,
+    s_final: Vec<S>,
+) -> ResultatCibles<S, A>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
+    <A as TryFrom<i8>>::Error: Debug,
+{
+    let nb_cibles: usize = s_final.iter().collect::<HashSet<&S>>().len();
+    parcours_meilleur_d_abord(
+        graphe,
+        s_init,
+        s_final,
+        Some(nb_cibles),
+        |dist: &A, _sommet: &S| dist.clone(),
+    )
+}
+
+// Dijkstra jusqu'à atteindre les 'k' sommets finaux les plus proches (moins s'il y en a moins d'accessibles)
+// Mêmes sorties que resoud_dijstra_toutes_cibles
+pub fn resoud_dijstra_k_plus_proches<G, S, A>(
+    graphe: &G,
+    s_init: Vec<S>,
+    s_final: Vec<S>,
+    k: usize,
+) -> ResultatCibles<S, A>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
+    <A as TryFrom<i8>>::Error: Debug,
+{
+    parcours_meilleur_d_abord(graphe, s_init, s_final, Some(k), |dist: &A, _sommet: &S| {
+        dist.clone()
+    })
+}
+
+// Implémentation de l'algorithme A* (A étoile)
+// https://fr.wikipedia.org/wiki/Algorithme_A*
+// Comme Dijkstra, mais la priorité d'un sommet est: distance parcourue + heuristique(sommet)
+// où l'heuristique estime la distance restante jusqu'aux sommets terminaux.
+// Le chemin trouvé est optimal si l'heuristique est admissible (ne surestime jamais la distance restante)
+// et consistante (h(s) <= d(s, v) + h(v) pour tout voisin v de s), par ex: distance de Manhattan sur une grille.
+// Mêmes sorties que resoud_dijstra
+pub fn resoud_a_etoile<G, S, A, H>(
+    graphe: &G,
+    s_init: Vec<S>,
+    s_final: Vec<S>,
+    heuristique: H,
+) -> ResultatRecherche<S, A>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
+    <A as TryFrom<i8>>::Error: Debug,
+    H: Fn(&S) -> A,
+{
+    parcours_jusqu_a_la_premiere_cible(graphe, s_init, s_final, |dist: &A, sommet: &S| {
+        dist.clone() + heuristique(sommet)
+    })
+}
+
+// Recherche gloutonne 'meilleur d'abord' (greedy best-first search)
+// https://en.wikipedia.org/wiki/Best-first_search#Greedy_BFS
+// La priorité d'un sommet est uniquement l'heuristique: on se dirige toujours vers le sommet
+// qui semble le plus proche de l'arrivée. Souvent très rapide, mais le chemin trouvé n'est pas forcément optimal.
+// Mêmes sorties que resoud_dijstra
+pub fn resoud_glouton<G, S, A, H>(
+    graphe: &G,
+    s_init: Vec<S>,
+    s_final: Vec<S>,
+    heuristique: H,
+) -> ResultatRecherche<S, A>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
+    <A as TryFrom<i8>>::Error: Debug,
+    H: Fn(&S) -> A,
+{
+    parcours_jusqu_a_la_premiere_cible(graphe, s_init, s_final, |_dist: &A, sommet: &S| {
+        heuristique(sommet)
+    })
+}
+
+// Implémentation de l'algorithme de Bellman-Ford
+// https://fr.wikipedia.org/wiki/Algorithme_de_Bellman-Ford
+// Plus lent que Dijkstra, mais accepte les poids négatifs.
+// Entrées: Sommets de départ
+// Sorties: Distances des sommets accessibles, et sommets précédents (voir reconstruit_chemin)
+// Erreur si un cycle de poids négatif est accessible depuis les sommets de départ
+// (les plus courts chemins ne sont alors pas définis): le cycle est renvoyé dans l'erreur.
+// Complexité: O(V.E)
+pub fn resoud_bellman_ford<G, S, A>(graphe: &G, s_init: Vec<S>) -> ResultatPlusCourtsChemins<S, A>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add + TryFrom<i8> + Clone + Debug + Add<Output = A>,
+    <A as TryFrom<i8>>::Error: Debug,
+{
+    let sommets: Vec<S> = graphe.liste_sommets();
+    let n_sommets = sommets.len();
+
+    // Distances provisoires des sommets atteints, et sommets précédents
+    let mut d: HashMap<S, A> = HashMap::new();
+    let mut prec: HashMap<S, S> = HashMap::new();
+
+    let dist_zero: A = A::try_from(0i8).expect("Distance nulle manquante pour le type A.");
+    for sommet in s_init {
+        d.insert(sommet, dist_zero.clone());
+    }
+
+    // Un plus court chemin a au plus V-1 arêtes: V-1 passes de relâchement de toutes les arêtes suffisent
+    // (arrêt anticipé si une passe ne modifie rien). Si la V-ième passe modifie encore
+    // une distance, c'est qu'un cycle négatif est accessible.
+    let mut sommet_modifie_opt: Option<S> = None;
+    for _passe in (0..n_sommets) {
+        sommet_modifie_opt = None;
+        for sommet in sommets.iter() {
+            let dist_sommet: A = match (d.get(sommet)) {
+                Some(dist) => dist.clone(),
+                None => continue,
+            };
+            for (voisin, voisin_dist) in graphe.liste_voisins_et_distances(sommet) {
+                let dist_nouveau_possible = dist_sommet.clone() + voisin_dist;
+                if let Some(dist_cour) = d.get(&voisin) {
+                    if (&dist_nouveau_possible >= dist_cour) {
+                        continue;
+                    }
+                }
+                d.insert(voisin.clone(), dist_nouveau_possible);
                 prec.insert(voisin.clone(), sommet.clone());
+                sommet_modifie_opt = Some(voisin);
             }
         }
+        if (sommet_modifie_opt.is_none()) {
+            break;
+        }
+    }
+
+    let sommet_modifie = match (sommet_modifie_opt) {
+        None => return Ok((d, prec)),
+        Some(sommet) => sommet,
+    };
+
+    // En remontant V fois les sommets précédents depuis un sommet modifié à la V-ième passe,
+    // on est forcément sur le cycle négatif
+    let mut sommet_cycle: S = sommet_modifie;
+    for _ in (0..n_sommets) {
+        sommet_cycle = prec[&sommet_cycle].clone();
+    }
+
+    // Parcours du cycle (à l'envers), puis remise dans l'ordre
+    let mut cycle: Vec<S> = vec![sommet_cycle.clone()];
+    let mut sommet_cour: S = prec[&sommet_cycle].clone();
+    while (sommet_cour != sommet_cycle) {
+        cycle.push(sommet_cour.clone());
+        sommet_cour = prec[&sommet_cour].clone();
+    }
+    cycle.push(sommet_cycle);
+    cycle.reverse();
+
+    Err(ErreurGraphe::CycleNegatif(cycle))
+}
+
+// Chemin entre un sommet initial et un sommet final, avec son coût total
+#[derive(Clone, Debug, PartialEq)]
+pub struct Chemin<S, A> {
+    // Sommets dans l'ordre de parcours: du sommet initial au sommet final (inclus)
+    pub sommets: Vec<S>,
+    pub cout: A,
+}
+
+// Reconstruit le chemin menant à 'sommet_final', à partir des sorties de resoud_dijstra
+// (ou resoud_a_etoile, resoud_glouton): on remonte les sommets précédents jusqu'à un sommet
+// sans précédent (= un sommet initial), puis on renverse la liste.
+// Renvoie None si 'sommet_final' n'a pas été atteint (absent des distances 'p')
+pub fn reconstruit_chemin<S, A>(
+    p: &HashMap<S, A>,
+    prec: &HashMap<S, S>,
+    sommet_final: &S,
+) -> Option<Chemin<S, A>>
+where
+    S: Eq + Hash + Clone,
+    A: Clone,
+{
+    let cout: A = p.get(sommet_final)?.clone();
+
+    let mut sommets: Vec<S> = vec![sommet_final.clone()];
+    let mut sommet_cour: &S = sommet_final;
+    while let Some(sommet_prec) = prec.get(sommet_cour) {
+        sommets.push(sommet_prec.clone());
+        sommet_cour = sommet_prec;
     }
+    sommets.reverse();
 
-    (p, prec, sommet_final_opt)
+    Some(Chemin { sommets, cout })
 }
 
 /*
@@ -135,47 +510,157 @@ where S : Eq + Hash + Voisins<S, A> + Clone, A : PartialOrd + Add + TryFrom<i8>
 <A as TryFrom<i8>>::Error: Debug,
 */
 
+// Direction de sortie d'une case à sens unique
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    Haut,
+    Bas,
+    Gauche,
+    Droite,
+}
+
+impl Direction {
+    // Décalage (hauteur, largeur) d'un déplacement dans cette direction
+    fn decalage(&self) -> (isize, isize) {
+        match (self) {
+            Direction::Haut => (-1, 0),
+            Direction::Bas => (1, 0),
+            Direction::Gauche => (0, -1),
+            Direction::Droite => (0, 1),
+        }
+    }
+}
+
+// Légende du plan d'un labyrinthe: caractères traversables, avec le coût pour entrer dans la case
+// Les caractères absents de la légende sont infranchissables (murs)
+#[derive(Clone, Debug)]
+pub struct Legende {
+    couts: HashMap<char, u64>,
+    // Cases à sens unique (flèches): on ne peut en sortir que dans la direction indiquée
+    sens_uniques: HashMap<char, Direction>,
+}
+
+impl Legende {
+    // Légende des plans de labyrinthes: ' ', '@' et '$' traversables, avec un coût de 1
+    pub fn standard() -> Self {
+        let mut legende = Legende {
+            couts: HashMap::new(),
+            sens_uniques: HashMap::new(),
+        };
+        for caractere in [' ', '@', '$'] {
+            legende.definit_cout(caractere, 1);
+        }
+        legende
+    }
+
+    // Légende des cartes de terrain: la légende standard, plus
+    // les chiffres '1' à '9' (coût = le chiffre) et les flèches '^', 'v', '<', '>' (sens uniques)
+    pub fn terrain() -> Self {
+        let mut legende = Self::standard();
+        for cout in (1u64..=9) {
+            let caractere = char::from_digit(cout as u32, 10).expect("Chiffre invalide.");
+            legende.definit_cout(caractere, cout);
+        }
+        legende.definit_sens_unique('^', Direction::Haut);
+        legende.definit_sens_unique('v', Direction::Bas);
+        legende.definit_sens_unique('<', Direction::Gauche);
+        legende.definit_sens_unique('>', Direction::Droite);
+        legende
+    }
+
+    // Rend le caractère traversable, avec le coût donné pour entrer dans la case
+    pub fn definit_cout(&mut self, caractere: char, cout: u64) {
+        self.couts.insert(caractere, cout);
+    }
+
+    // Rend le caractère traversable en sens unique (avec un coût de 1, s'il n'en avait pas)
+    pub fn definit_sens_unique(&mut self, caractere: char, direction: Direction) {
+        self.couts.entry(caractere).or_insert(1);
+        self.sens_uniques.insert(caractere, direction);
+    }
+
+    // Coût pour entrer dans une case, None si elle est infranchissable
+    pub fn cout(&self, caractere: char) -> Option<u64> {
+        self.couts.get(&caractere).copied()
+    }
+
+    pub fn sens_unique(&self, caractere: char) -> Option<Direction> {
+        self.sens_uniques.get(&caractere).copied()
+    }
+
+    // Plus petit coût d'une case (pour garder les heuristiques admissibles)
+    fn cout_min(&self) -> u64 {
+        self.couts.values().copied().min().unwrap_or(0)
+    }
+}
+
+impl Default for Legende {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+// Coûts des déplacements en mode 8 voisins: 1000 pour un déplacement orthogonal, et 1415 en diagonale
+// (1.415 est une approximation par excès de √2: l'heuristique euclidienne reste admissible)
+pub const COUT_ORTHOGONAL_DIAGONALES: u64 = 1000;
+pub const COUT_DIAGONAL: u64 = 1415;
+
+// Position d'une case du labyrinthe: ligne (de haut en bas) et colonne (de gauche à droite)
+// L'ordre (ligne, puis colonne) est celui de lecture du plan
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub ligne: usize,
+    pub colonne: usize,
+}
+
+impl Position {
+    pub fn new(ligne: usize, colonne: usize) -> Self {
+        Position { ligne, colonne }
+    }
+
+    // Position décalée de (dl, dc), None si elle sort du plan par le haut ou par la gauche
+    pub fn decale(&self, dl: isize, dc: isize) -> Option<Position> {
+        Some(Position {
+            ligne: self.ligne.checked_add_signed(dl)?,
+            colonne: self.colonne.checked_add_signed(dc)?,
+        })
+    }
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(ligne {}, colonne {})", self.ligne, self.colonne)
+    }
+}
+
 pub struct Labyrinthe {
-    plan: Vec<String>,
-    hauteur: u32,
-    largeur: u32,
-    s_init: Vec<u64>,
-    s_final: Vec<u64>,
+    // Plan sous forme de grille: grille[ligne][colonne]
+    grille: Vec<Vec<char>>,
+    hauteur: usize,
+    largeur: usize,
+    s_init: Vec<Position>,
+    s_final: Vec<Position>,
 
     caractere_init: char,
     caractere_final: char,
+
+    legende: Legende,
+    // Déplacements dans les 8 directions (sinon: haut, bas, gauche, droite)
+    diagonales: bool,
 }
 
 impl Labyrinthe {
     // Fonctions statiques
-    pub fn u64_vers_hauteur_largeur(pos: u64) -> (u32, u32) {
-        let puissance_separateur: u32 = 32;
-        let deux_puiss: u64 = u64::pow(2, puissance_separateur);
-        let deux_puiss_moins_1: u64 = deux_puiss - 1;
-        ((pos / deux_puiss) as u32, (pos & deux_puiss_moins_1) as u32)
-    }
-
-    pub fn hauteur_largeur_vers_u64(hauteur: u32, largeur: u32) -> u64 {
-        let puissance_separateur: u32 = 32;
-        let deux_puiss: u64 = u64::pow(2, puissance_separateur);
-        (hauteur as u64) * deux_puiss + (largeur as u64)
-    }
-
-    fn trouve_caractere_dans_le_plan(
-        plan: &[String],
-        largeur: u32,
-        hauteur: u32,
+    fn trouve_caractere_dans_la_grille(
+        grille: &[Vec<char>],
         caractere_a_trouver: char,
-    ) -> Vec<u64> {
-        let mut pos_vec: Vec<u64> = Vec::new();
-
-        for index_hauteur in (0u32..hauteur) {
-            let ligne_cour: Vec<char> = plan[index_hauteur as usize].chars().collect::<Vec<_>>();
-            for index_largeur in (0u32..largeur) {
-                let caractere_cour = ligne_cour[index_largeur as usize];
-                if (caractere_a_trouver == caractere_cour) {
-                    let pos: u64 = Self::hauteur_largeur_vers_u64(index_hauteur, index_largeur);
-                    pos_vec.push(pos);
+    ) -> Vec<Position> {
+        let mut pos_vec: Vec<Position> = Vec::new();
+
+        for (index_ligne, ligne) in grille.iter().enumerate() {
+            for (index_colonne, caractere_cour) in ligne.iter().enumerate() {
+                if (caractere_a_trouver == *caractere_cour) {
+                    pos_vec.push(Position::new(index_ligne, index_colonne));
                 }
             }
         }
@@ -184,51 +669,97 @@ impl Labyrinthe {
     }
 
     // Fonctions publiques
-    // Constructeur
+    // Constructeur: légende standard, déplacements dans les 4 directions avec un coût de 1
     pub fn new(plan: &[String]) -> Self {
-        let s_plan = plan.to_owned();
+        Self::avec_legende(plan, Legende::standard(), false)
+    }
 
-        let s_hauteur = s_plan.len() as u32;
-        assert!(
-            s_hauteur >= 1,
-            "Erreur: Le labyrinthe doit avoir au moins une ligne"
-        );
-        let s_largeur = s_plan[0].len() as u32;
-        for i in (1..s_hauteur) {
-            let largeur_cour: u32 = s_plan[i as usize].len() as u32;
-            assert_eq!(
-                largeur_cour, s_largeur,
-                "Erreur: La largeur doit etre la meme pour toutes les lignes"
-            );
+    // Constructeur avec une légende (coûts des cases, sens uniques..)
+    // et éventuellement les déplacements en diagonale
+    // Avec les diagonales, le coût d'un déplacement est le coût de la case d'arrivée,
+    // multiplié par COUT_ORTHOGONAL_DIAGONALES ou COUT_DIAGONAL.
+    // Un déplacement en diagonale n'est possible que si les 2 cases qu'il longe sont traversables.
+    // Pour un plan construit par le programme (ex: genere_plan_aleatoire): panique si le plan est invalide.
+    // Pour un plan lu dans un fichier, utiliser depuis_plan.
+    pub fn avec_legende(plan: &[String], legende: Legende, diagonales: bool) -> Self {
+        Self::depuis_plan(plan, legende, diagonales, "<plan>")
+            .unwrap_or_else(|erreur| panic!("Erreur: {}", erreur))
+    }
+
+    // Constructeur faillible, pour un plan lu dans le fichier 'nom_plan' (utilisé dans les messages d'erreur)
+    // Les lignes vides en fin de plan sont ignorées (ex: fichier terminé par un retour à la ligne)
+    // Erreur::FormatInvalide si le plan est vide, ou si ses lignes n'ont pas toutes la même largeur
+    pub fn depuis_plan(
+        plan: &[String],
+        legende: Legende,
+        diagonales: bool,
+        nom_plan: &str,
+    ) -> Result<Self, Erreur> {
+        let nb_lignes: usize = plan
+            .iter()
+            .rposition(|ligne| !ligne.is_empty())
+            .map_or(0, |index| index + 1);
+        let s_grille: Vec<Vec<char>> = plan[..nb_lignes]
+            .iter()
+            .map(|ligne| ligne.chars().collect::<Vec<char>>())
+            .collect();
+
+        let s_hauteur = s_grille.len();
+        if (s_hauteur == 0) {
+            return Err(Erreur::FormatInvalide(
+                nom_plan.to_string(),
+                1,
+                "Le labyrinthe doit avoir au moins une ligne".to_string(),
+            ));
+        }
+        let s_largeur = s_grille[0].len();
+        if let Some(index_ligne) = s_grille.iter().position(|ligne| ligne.len() != s_largeur) {
+            return Err(Erreur::FormatInvalide(
+                nom_plan.to_string(),
+                index_ligne + 1,
+                format!(
+                    "La largeur doit etre la meme pour toutes les lignes ({} au lieu de {})",
+                    s_grille[index_ligne].len(),
+                    s_largeur
+                ),
+            ));
         }
 
         let s_caractere_init = '@';
         let s_caractere_final = '$';
-        let s_s_init =
-            Self::trouve_caractere_dans_le_plan(&s_plan, s_largeur, s_hauteur, s_caractere_init);
-        let s_s_final =
-            Self::trouve_caractere_dans_le_plan(&s_plan, s_largeur, s_hauteur, s_caractere_final);
+        let s_s_init = Self::trouve_caractere_dans_la_grille(&s_grille, s_caractere_init);
+        let s_s_final = Self::trouve_caractere_dans_la_grille(&s_grille, s_caractere_final);
 
-        Labyrinthe {
-            plan: s_plan,
+        Ok(Labyrinthe {
+            grille: s_grille,
             hauteur: s_hauteur,
             largeur: s_largeur,
             s_init: s_s_init,
             s_final: s_s_final,
             caractere_init: s_caractere_init,
             caractere_final: s_caractere_final,
-        }
+            legende,
+            diagonales,
+        })
     }
 
     // Getters/Setters
-    pub fn s_init(&self) -> Vec<u64> {
+    pub fn s_init(&self) -> Vec<Position> {
         self.s_init.clone()
     }
 
-    pub fn s_final(&self) -> Vec<u64> {
+    pub fn s_final(&self) -> Vec<Position> {
         self.s_final.clone()
     }
 
+    pub fn hauteur(&self) -> usize {
+        self.hauteur
+    }
+
+    pub fn largeur(&self) -> usize {
+        self.largeur
+    }
+
     pub fn caractere_init(&self) -> char {
         self.caractere_init
     }
@@ -236,52 +767,191 @@ impl Labyrinthe {
     pub fn caractere_final(&self) -> char {
         self.caractere_final
     }
-}
 
-impl Voisins<u64, u64> for Labyrinthe {
-    fn liste_voisins_et_distances(&self, pos: &u64) -> Vec<(u64, u64)> {
-        let mut voisins: Vec<(u64, u64)> = Vec::new();
+    pub fn legende(&self) -> &Legende {
+        &self.legende
+    }
 
-        // Voisins possibles = Les 4 directions (haut, bas, gauche, droite) à une distance de 1
-        let (hauteur, largeur): (u32, u32) = Self::u64_vers_hauteur_largeur(*pos);
-        let mut voisins_possibles: Vec<(u64, u64)> = Vec::new();
+    pub fn diagonales(&self) -> bool {
+        self.diagonales
+    }
+
+    // Caractère du plan à une position (None en dehors du plan)
+    pub fn caractere(&self, pos: Position) -> Option<char> {
+        self.grille.get(pos.ligne)?.get(pos.colonne).copied()
+    }
 
-        if (hauteur > 0) {
-            voisins_possibles.push((Self::hauteur_largeur_vers_u64(hauteur - 1, largeur), 1));
+    // Coût pour entrer dans la case d'une position (None si infranchissable ou en dehors du plan)
+    fn cout_case(&self, pos: Position) -> Option<u64> {
+        self.legende.cout(self.caractere(pos)?)
+    }
+
+    // Heuristique pour A* et la recherche gloutonne:
+    // distance (à vol d'oiseau) de la position à la plus proche des positions finales,
+    // multipliée par le plus petit coût d'une case (et par COUT_ORTHOGONAL_DIAGONALES avec les diagonales)
+    pub fn heuristique(&self, heuristique: Heuristique) -> impl Fn(&Position) -> u64 + '_ {
+        let cout_min: u64 = self.legende.cout_min();
+        let (cout_orthogonal, cout_diagonal): (u64, u64) = if (self.diagonales) {
+            (COUT_ORTHOGONAL_DIAGONALES, COUT_DIAGONAL)
+        } else {
+            // Sans diagonale, un déplacement en diagonale coûte 2 déplacements orthogonaux
+            (1, 2)
+        };
+        move |pos: &Position| -> u64 {
+            self.s_final
+                .iter()
+                .map(|pos_final| match (heuristique) {
+                    Heuristique::Manhattan => {
+                        cout_orthogonal * distance_manhattan(*pos, *pos_final)
+                    }
+                    Heuristique::Euclidienne => {
+                        distance_euclidienne(*pos, *pos_final, cout_orthogonal)
+                    }
+                    Heuristique::Octile => {
+                        distance_octile(*pos, *pos_final, cout_orthogonal, cout_diagonal)
+                    }
+                })
+                .min()
+                .map(|distance| cout_min * distance)
+                .unwrap_or(0)
         }
-        voisins_possibles.push((Self::hauteur_largeur_vers_u64(hauteur + 1, largeur), 1));
-        if (largeur > 0) {
-            voisins_possibles.push((Self::hauteur_largeur_vers_u64(hauteur, largeur - 1), 1));
+    }
+}
+
+// Heuristiques disponibles pour les positions du labyrinthe
+// Dans les 4 directions, les trois sont admissibles et consistantes (Manhattan = Octile est la plus précise,
+// donc A* explore moins de cases). Avec les diagonales, Manhattan surestime les distances:
+// le chemin trouvé par A* n'est alors plus forcément optimal (utiliser Octile).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Heuristique {
+    Manhattan,
+    Euclidienne,
+    Octile,
+}
+
+// Algorithme de recherche de chemin utilisé par resoud_labyrinthe
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AlgoRecherche {
+    // Parcours en largeur: suffisant quand toutes les cases ont le même coût (la légende standard)
+    Largeur,
+    Dijkstra,
+    AEtoile(Heuristique),
+    Glouton(Heuristique),
+}
+
+impl std::fmt::Display for AlgoRecherche {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self) {
+            AlgoRecherche::Largeur => write!(f, "parcours en largeur"),
+            AlgoRecherche::Dijkstra => write!(f, "Dijkstra"),
+            AlgoRecherche::AEtoile(heuristique) => write!(f, "A* - {:?}", heuristique),
+            AlgoRecherche::Glouton(heuristique) => write!(f, "glouton - {:?}", heuristique),
         }
-        voisins_possibles.push((Self::hauteur_largeur_vers_u64(hauteur, largeur + 1), 1));
+    }
+}
 
-        // On peut traverser une case du labyrinthe qui contient:
-        // soit un espace ' ', soit le caracteres initial ou final (car n'étant pas des murs)
-        let caracteres_passage: Vec<char> = vec![' ', self.caractere_init, self.caractere_final];
+// Écarts (en lignes et en colonnes) entre 2 positions
+fn ecarts(pos1: Position, pos2: Position) -> (u64, u64) {
+    (
+        pos1.ligne.abs_diff(pos2.ligne) as u64,
+        pos1.colonne.abs_diff(pos2.colonne) as u64,
+    )
+}
 
-        for (voisin, dist) in voisins_possibles {
-            let (hauteur_v, largeur_v): (u32, u32) = Self::u64_vers_hauteur_largeur(voisin);
-            if (hauteur_v >= self.hauteur) {
-                continue;
-            }
-            if (largeur_v >= self.largeur) {
-                continue;
+// Distance de Manhattan entre 2 positions du labyrinthe: |dx| + |dy|
+pub fn distance_manhattan(pos1: Position, pos2: Position) -> u64 {
+    let (dl, dc) = ecarts(pos1, pos2);
+    dl + dc
+}
+
+// Distance euclidienne entre 2 positions du labyrinthe, multipliée par 'echelle' et arrondie à l'entier inférieur
+// (l'arrondi inférieur garde l'heuristique admissible avec des distances entières)
+pub fn distance_euclidienne(pos1: Position, pos2: Position, echelle: u64) -> u64 {
+    let (dl, dc) = ecarts(pos1, pos2);
+    let (dl, dc) = (dl as f64, dc as f64);
+    (echelle as f64 * f64::sqrt(dl * dl + dc * dc)).floor() as u64
+}
+
+// Distance octile entre 2 positions: longueur du plus court trajet sans obstacle
+// avec des déplacements orthogonaux et diagonaux de coûts respectifs 'cout_orthogonal' et 'cout_diagonal'
+pub fn distance_octile(
+    pos1: Position,
+    pos2: Position,
+    cout_orthogonal: u64,
+    cout_diagonal: u64,
+) -> u64 {
+    let (dl, dc) = ecarts(pos1, pos2);
+    let (d_min, d_max) = (dl.min(dc), dl.max(dc));
+    cout_diagonal * d_min + cout_orthogonal * (d_max - d_min)
+}
+
+// Sommets du labyrinthe: toutes les cases traversables
+impl Sommets<Position> for Labyrinthe {
+    fn liste_sommets(&self) -> Vec<Position> {
+        let mut sommets: Vec<Position> = Vec::new();
+        for (index_ligne, ligne) in self.grille.iter().enumerate() {
+            for (index_colonne, caractere) in ligne.iter().enumerate() {
+                if (self.legende.cout(*caractere).is_some()) {
+                    sommets.push(Position::new(index_ligne, index_colonne));
+                }
             }
+        }
+        sommets
+    }
+}
 
-            let case_cour: char =
-                self.plan[hauteur_v as usize].chars().collect::<Vec<_>>()[largeur_v as usize];
-            if (!caracteres_passage.contains(&case_cour)) {
-                continue;
+impl Voisins<Position, u64> for Labyrinthe {
+    fn liste_voisins_et_distances(&self, pos: &Position) -> Vec<(Position, u64)> {
+        let mut voisins: Vec<(Position, u64)> = Vec::new();
+
+        // Directions possibles: (haut, bas, gauche, droite), plus les diagonales si activées,
+        // ou uniquement la direction de la flèche pour une case à sens unique
+        let mut directions: Vec<(isize, isize)> = vec![(-1, 0), (1, 0), (0, -1), (0, 1)];
+        if (self.diagonales) {
+            directions.extend_from_slice(&[(-1, -1), (-1, 1), (1, -1), (1, 1)]);
+        }
+        if let Some(caractere_cour) = self.caractere(*pos) {
+            if let Some(direction) = self.legende.sens_unique(caractere_cour) {
+                directions = vec![direction.decalage()];
             }
-            voisins.push((voisin, dist));
+        }
+
+        for (dl, dc) in directions {
+            let pos_v: Position = match (pos.decale(dl, dc)) {
+                Some(pos_v) => pos_v,
+                None => continue,
+            };
+
+            // On peut traverser une case si son caractère est dans la légende
+            // (par défaut: un espace ' ', ou les caracteres initial et final, n'étant pas des murs)
+            let cout_case: u64 = match (self.cout_case(pos_v)) {
+                Some(cout) => cout,
+                None => continue,
+            };
+
+            let dist: u64 = if (!self.diagonales) {
+                cout_case
+            } else if (dl != 0 && dc != 0) {
+                // Pas de passage en diagonale entre 2 murs (ni en coupant un coin)
+                let longe_ligne = Position::new(pos_v.ligne, pos.colonne);
+                let longe_colonne = Position::new(pos.ligne, pos_v.colonne);
+                if (self.cout_case(longe_ligne).is_none()
+                    || self.cout_case(longe_colonne).is_none())
+                {
+                    continue;
+                }
+                cout_case * COUT_DIAGONAL
+            } else {
+                cout_case * COUT_ORTHOGONAL_DIAGONALES
+            };
+            voisins.push((pos_v, dist));
         }
 
         // Pour debuggage
         if (false) {
-            println!("\nVoisins de la position: ({}, {})", largeur, hauteur);
+            println!("\nVoisins de la position: {}", pos);
             for (pos, _dist) in &voisins {
-                let (hauteur, largeur) = Labyrinthe::u64_vers_hauteur_largeur(*pos);
-                println!("(x,y) = ({},{})", largeur, hauteur);
+                println!("{}", pos);
             }
         }
 
@@ -289,101 +959,252 @@ impl Voisins<u64, u64> for Labyrinthe {
     }
 }
 
-pub fn resoud_labyrinthe(f_plan_labyrinthe: String, f_plan_solution: String) {
+// Génère le plan d'un grand labyrinthe aléatoire (pour les mesures de performance)
+// Grille entourée de murs, dont chaque case intérieure est un mur avec la probabilité 'proportion_murs'
+// Le départ '@' est en haut à gauche, l'arrivée '$' en bas à droite
+pub fn genere_plan_aleatoire(
+    hauteur: usize,
+    largeur: usize,
+    proportion_murs: f64,
+    seed: u32,
+) -> Vec<String> {
+    assert!(
+        hauteur >= 3 && largeur >= 3,
+        "Erreur: Le labyrinthe doit mesurer au moins 3x3"
+    );
+    let mut rng: RngMinstd = RngMinstd::new(seed);
+    let seuil_mur: u32 = (proportion_murs * (u32::MAX >> 1) as f64) as u32;
+
+    let mut plan: Vec<String> = Vec::new();
+    for index_hauteur in 0..hauteur {
+        let mut ligne_cour: String = String::new();
+        for index_largeur in 0..largeur {
+            let bord: bool = (index_hauteur == 0
+                || index_hauteur == hauteur - 1
+                || index_largeur == 0
+                || index_largeur == largeur - 1);
+            let caractere: char = if (bord) {
+                '#'
+            } else if (index_hauteur == 1 && index_largeur == 1) {
+                '@'
+            } else if (index_hauteur == hauteur - 2 && index_largeur == largeur - 2) {
+                '$'
+            } else if (rng.gen() < seuil_mur) {
+                '#'
+            } else {
+                ' '
+            };
+            ligne_cour.push(caractere);
+        }
+        plan.push(ligne_cour);
+    }
+
+    plan
+}
+
+// Taille (en pixels) d'un caractère du plan, dans les images des labyrinthes
+const TAILLE_CASE_IMAGE: u32 = 10;
+
+// Couleur (rouge, vert, bleu) d'un caractère du plan solution dans les images
+fn couleur_caractere_plan(caractere: char) -> (u8, u8, u8) {
+    match (caractere) {
+        ' ' => (255, 255, 255),
+        'o' => (200, 200, 200),
+        'x' => (255, 200, 0),
+        '@' => (30, 90, 255),
+        '$' => (0, 170, 0),
+        // Murs
+        _ => (0, 0, 0),
+    }
+}
+
+// Dessine le plan d'un labyrinthe (éventuellement avec les marques 'o' et 'x' de la solution) en SVG
+pub fn dessine_labyrinthe_svg(plan: &[String], fichier_chemin: &String) -> Result<(), Erreur> {
+    let hauteur: u32 = plan.len() as u32;
+    let largeur: u32 = plan
+        .iter()
+        .map(|ligne| ligne.chars().count())
+        .max()
+        .unwrap_or(0) as u32;
+
+    // Fond blanc, puis un carré par caractère non vide
+    let mut figures: Vec<Box<dyn Vectorisable>> = vec![Box::new(Rectangle::new(
+        0,
+        0,
+        largeur * TAILLE_CASE_IMAGE,
+        hauteur * TAILLE_CASE_IMAGE,
+        "white",
+    ))];
+    for (index_hauteur, ligne) in plan.iter().enumerate() {
+        for (index_largeur, caractere) in ligne.chars().enumerate() {
+            if (caractere == ' ') {
+                continue;
+            }
+            let (rouge, vert, bleu) = couleur_caractere_plan(caractere);
+            figures.push(Box::new(Rectangle::new(
+                index_largeur as u32 * TAILLE_CASE_IMAGE,
+                index_hauteur as u32 * TAILLE_CASE_IMAGE,
+                TAILLE_CASE_IMAGE,
+                TAILLE_CASE_IMAGE,
+                &format!("rgb({},{},{})", rouge, vert, bleu),
+            )));
+        }
+    }
+
+    fractales::cree_fichier_svg(
+        fichier_chemin,
+        hauteur * TAILLE_CASE_IMAGE,
+        largeur * TAILLE_CASE_IMAGE,
+        &figures,
+    )
+}
+
+// Dessine le plan d'un labyrinthe en image matricielle BMP
+pub fn dessine_labyrinthe_bmp(plan: &[String], fichier_chemin: &String) -> Result<(), Erreur> {
+    let hauteur: u32 = plan.len() as u32;
+    let largeur: u32 = plan
+        .iter()
+        .map(|ligne| ligne.chars().count())
+        .max()
+        .unwrap_or(0) as u32;
+
+    let mut pixels: Vec<(u8, u8, u8)> = Vec::new();
+    for ligne in plan {
+        let mut pixels_ligne: Vec<(u8, u8, u8)> = ligne
+            .chars()
+            .flat_map(|caractere| {
+                vec![couleur_caractere_plan(caractere); TAILLE_CASE_IMAGE as usize]
+            })
+            .collect();
+        // Lignes plus courtes complétées en blanc
+        pixels_ligne.resize((largeur * TAILLE_CASE_IMAGE) as usize, (255, 255, 255));
+        for _ in (0..TAILLE_CASE_IMAGE) {
+            pixels.extend_from_slice(&pixels_ligne);
+        }
+    }
+
+    fractales::ecrit_bmp(
+        fichier_chemin,
+        largeur * TAILLE_CASE_IMAGE,
+        hauteur * TAILLE_CASE_IMAGE,
+        &pixels,
+    )
+}
+
+// Résout le labyrinthe du fichier 'f_plan_labyrinthe', et écrit la solution dans 'f_plan_solution'
+// avec l'algorithme 'algo' (Dijkstra, A* ou glouton): les cases parcourues sont marquées 'o',
+// ce qui permet de comparer les algorithmes.
+// La légende (coûts des cases) et les diagonales sont celles de Labyrinthe::avec_legende
+// Si 'images' est vrai, la solution est aussi dessinée en SVG et BMP, à côté du fichier solution
+// (ex: labyrinthe1_solution.txt -> labyrinthe1_solution.svg et labyrinthe1_solution.bmp)
+// Les erreurs de lecture / écriture des fichiers sont renvoyées à l'appelant
+pub fn resoud_labyrinthe(
+    f_plan_labyrinthe: String,
+    f_plan_solution: String,
+    algo: AlgoRecherche,
+    legende: &Legende,
+    diagonales: bool,
+    images: bool,
+) -> Result<(), Erreur> {
     let plan_labyrinthe: Vec<String> =
-        fichiers::lire_fichier_texte_lignes(&f_plan_labyrinthe, None);
-    let labyrinthe: Labyrinthe = Labyrinthe::new(&plan_labyrinthe);
+        fichiers::lire_fichier_texte_lignes(&f_plan_labyrinthe, None)?;
+    let labyrinthe: Labyrinthe = Labyrinthe::depuis_plan(
+        &plan_labyrinthe,
+        legende.clone(),
+        diagonales,
+        &f_plan_labyrinthe,
+    )?;
 
-    let s_init: Vec<u64> = labyrinthe.s_init();
-    let s_final: Vec<u64> = labyrinthe.s_final();
+    let s_init: Vec<Position> = labyrinthe.s_init();
+    let s_final: Vec<Position> = labyrinthe.s_final();
 
     println!("\nPosition(s) initiale(s):");
     for pos in &s_init {
-        let (hauteur, largeur) = Labyrinthe::u64_vers_hauteur_largeur(*pos);
-        println!("(x,y) = ({},{})", largeur, hauteur);
+        println!("{}", pos);
     }
 
     println!("\nPosition(s) finale(s):");
     for pos in &s_final {
-        let (hauteur, largeur) = Labyrinthe::u64_vers_hauteur_largeur(*pos);
-        println!("(x,y) = ({},{})", largeur, hauteur);
+        println!("{}", pos);
     }
 
     //let (HashMap<S, A>, HashMap<S, S>, Option<S>)
 
-    let (p, prec, sommet_final_opt) = resoud_dijstra(&labyrinthe, s_init, s_final);
+    let resultat = match (algo) {
+        // (coûts réels du chemin trouvé: les cases et les diagonales n'ont pas toutes le même coût)
+        AlgoRecherche::Largeur => Ok(resoud_largeur_avec_couts(
+            &labyrinthe,
+            s_init.clone(),
+            s_final.clone(),
+        )),
+        AlgoRecherche::Dijkstra => resoud_dijstra(&labyrinthe, s_init.clone(), s_final.clone()),
+        AlgoRecherche::AEtoile(heuristique) => resoud_a_etoile(
+            &labyrinthe,
+            s_init.clone(),
+            s_final.clone(),
+            labyrinthe.heuristique(heuristique),
+        ),
+        AlgoRecherche::Glouton(heuristique) => resoud_glouton(
+            &labyrinthe,
+            s_init.clone(),
+            s_final.clone(),
+            labyrinthe.heuristique(heuristique),
+        ),
+    };
+    let (p, prec, sommet_final_opt) =
+        resultat.map_err(|erreur| Erreur::Graphe(erreur.to_string()))?;
+    println!("\nNombre de cases parcourues ({}): {}", algo, p.len());
     //println!("P: {:?}", P);
     //println!("\nSommet_final_opt: {:?}", sommet_final_opt);
 
     if (false) {
         println!("\nDistance de chaque sommet au(x) point(s) initial(aux):");
         for (pos, dist) in &p {
-            let (hauteur, largeur) = Labyrinthe::u64_vers_hauteur_largeur(*pos);
-            println!("(x,y) = ({},{}) a une distance {}", largeur, hauteur, dist);
+            println!("{} a une distance {}", pos, dist);
         }
     }
 
-    if (sommet_final_opt.is_some()) {
-        let sommet_final = sommet_final_opt.unwrap();
-        let dist_final = p[&sommet_final];
-        let (hauteur, largeur) = Labyrinthe::u64_vers_hauteur_largeur(sommet_final);
+    if let Some(chemin) = sommet_final_opt.and_then(|sommet| reconstruit_chemin(&p, &prec, &sommet))
+    {
+        let sommet_final: Position = *chemin.sommets.last().expect("Chemin vide.");
         println!(
-            "Sommet final ({}, {}) a une distance de : {}",
-            largeur, hauteur, dist_final
+            "Sommet final {} a une distance de : {}",
+            sommet_final, chemin.cout
         );
 
-        // Stocker tous les sommets parcourus
-        let mut sommets_parcourus: Vec<(u32, u32)> = Vec::new();
-        let s_init: Vec<u64> = labyrinthe.s_init();
-        let s_final: Vec<u64> = labyrinthe.s_final();
-        for (pos, _dist) in p {
-            if (s_init.contains(&pos)) {
-                continue;
-            }
-            if (s_final.contains(&pos)) {
+        // Marquer sur la grille tous les sommets parcourus, puis la solution:
+        // le chemin, sans ses extrémités ('@' et '$' restent visibles)
+        let mut grille_solution: Vec<Vec<char>> = labyrinthe.grille.clone();
+
+        let caractere_sommet_parcouru = 'o';
+        for pos in p.keys() {
+            if (s_init.contains(pos) || s_final.contains(pos)) {
                 continue;
             }
-            let (hauteur, largeur) = Labyrinthe::u64_vers_hauteur_largeur(pos);
-            sommets_parcourus.push((largeur, hauteur));
+            grille_solution[pos.ligne][pos.colonne] = caractere_sommet_parcouru;
         }
 
-        // Construire la solution
-        let mut chemin_solution: Vec<(u32, u32)> = Vec::new();
-        let mut sommet_cour = sommet_final;
-        let s_init: Vec<u64> = labyrinthe.s_init();
-        while (prec.contains_key(&sommet_cour)) {
-            //println!("Ajoute un point à la solution");
-            sommet_cour = prec[&sommet_cour];
-            if (s_init.contains(&sommet_cour)) {
-                continue;
-            }
-            let (hauteur, largeur) = Labyrinthe::u64_vers_hauteur_largeur(sommet_cour);
-            chemin_solution.push((largeur, hauteur));
+        let caractere_solution = 'x';
+        let n_sommets = chemin.sommets.len();
+        for pos in chemin.sommets.iter().take(n_sommets - 1).skip(1) {
+            grille_solution[pos.ligne][pos.colonne] = caractere_solution;
         }
 
         // Enregistrer la solution sur disque dur
-        let mut plan_solution = plan_labyrinthe.clone();
+        let plan_solution: Vec<String> = grille_solution
+            .iter()
+            .map(|ligne| ligne.iter().collect::<String>())
+            .collect();
+        fichiers::ecrire_fichier_texte_lignes(&f_plan_solution, &plan_solution)?;
 
-        let caractere_sommet_parcouru = 'o';
-        for (largeur, hauteur) in sommets_parcourus {
-            let mut ligne_cour: Vec<char> =
-                plan_solution[hauteur as usize].chars().collect::<Vec<_>>();
-            ligne_cour[largeur as usize] = caractere_sommet_parcouru;
-            let ligne_cour_string = ligne_cour.iter().collect::<String>();
-            plan_solution[hauteur as usize] = ligne_cour_string;
+        if (images) {
+            let base: &str = f_plan_solution
+                .strip_suffix(".txt")
+                .unwrap_or(&f_plan_solution);
+            dessine_labyrinthe_svg(&plan_solution, &(base.to_string() + ".svg"))?;
+            dessine_labyrinthe_bmp(&plan_solution, &(base.to_string() + ".bmp"))?;
         }
 
-        let caractere_solution = 'x';
-        for (largeur, hauteur) in chemin_solution {
-            let mut ligne_cour: Vec<char> =
-                plan_solution[hauteur as usize].chars().collect::<Vec<_>>();
-            ligne_cour[largeur as usize] = caractere_solution;
-            let ligne_cour_string = ligne_cour.iter().collect::<String>();
-            plan_solution[hauteur as usize] = ligne_cour_string;
-        }
-
-        fichiers::ecrire_fichier_texte_lignes(&f_plan_solution, &plan_solution);
-
         let caractere_init = labyrinthe.caractere_init();
         let caractere_final = labyrinthe.caractere_final();
         let caractere_sommet_parcouru_couleur = "\x1b[90mo\x1b[0m";
@@ -401,7 +1222,9 @@ pub fn resoud_labyrinthe(f_plan_labyrinthe: String, f_plan_solution: String) {
             plan_solution_couleur.push(ligne_couleur);
         }
         // Afficher la solution à l'écran en couleur
-        println!("Solution (via Dijkstra)");
+        println!("Solution (via {})", algo);
         println!("{}", plan_solution_couleur.join("\n"));
     }
+
+    Ok(())
 }
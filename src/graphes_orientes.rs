@@ -0,0 +1,260 @@
+// Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
+#![allow(unused_parens)]
+
+// Algorithmes sur les graphes orientés implémentant Voisins et Sommets
+// (ex: dépendances entre tâches, où un arc a -> b signifie que a doit être fait avant b):
+// tri topologique, détection de cycle, et composantes fortement connexes.
+// Les poids des arcs sont ignorés.
+// Les parcours en profondeur sont itératifs (pile explicite): pas de risque de débordement de pile.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::graphes::{ErreurGraphe, Sommets, Voisins};
+
+// Etat d'un sommet lors du parcours en profondeur
+#[derive(Clone, Copy, PartialEq)]
+enum EtatSommet {
+    // Sommet dans la pile d'appels: le retrouver comme voisin signifie qu'il y a un cycle
+    EnCours,
+    // Sommet et tous ses descendants parcourus
+    Termine,
+}
+
+// Parcours en profondeur de tout le graphe (les sommets de départ dans l'ordre de liste_sommets)
+// Renvoie les sommets dans l'ordre postfixe (un sommet après tous ses descendants),
+// ou le premier cycle rencontré (premier sommet répété à la fin)
+fn parcours_postfixe<G, S, A>(graphe: &G) -> Result<Vec<S>, Vec<S>>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    let mut etats: HashMap<S, EtatSommet> = HashMap::new();
+    let mut postfixe: Vec<S> = Vec::new();
+
+    for racine in graphe.liste_sommets() {
+        if (etats.contains_key(&racine)) {
+            continue;
+        }
+        etats.insert(racine.clone(), EtatSommet::EnCours);
+        let voisins_racine = graphe.liste_voisins_et_distances(&racine).into_iter();
+        // Pile d'appels: chaque sommet en cours, avec ses voisins restant à parcourir
+        let mut pile: Vec<(S, std::vec::IntoIter<(S, A)>)> = vec![(racine, voisins_racine)];
+
+        while let Some((sommet, voisins)) = pile.last_mut() {
+            match (voisins.next()) {
+                Some((voisin, _dist)) => match (etats.get(&voisin)) {
+                    None => {
+                        etats.insert(voisin.clone(), EtatSommet::EnCours);
+                        let voisins_voisin = graphe.liste_voisins_et_distances(&voisin).into_iter();
+                        pile.push((voisin, voisins_voisin));
+                    }
+                    Some(EtatSommet::EnCours) => {
+                        // Arc retour: le cycle est la fin de la pile d'appels, depuis 'voisin'
+                        let debut = pile
+                            .iter()
+                            .position(|(sommet_pile, _)| sommet_pile == &voisin)
+                            .expect("Sommet en cours absent de la pile.");
+                        let mut cycle: Vec<S> = pile[debut..]
+                            .iter()
+                            .map(|(sommet_pile, _)| sommet_pile.clone())
+                            .collect();
+                        cycle.push(voisin);
+                        return Err(cycle);
+                    }
+                    Some(EtatSommet::Termine) => {}
+                },
+                None => {
+                    etats.insert(sommet.clone(), EtatSommet::Termine);
+                    postfixe.push(sommet.clone());
+                    pile.pop();
+                }
+            }
+        }
+    }
+
+    Ok(postfixe)
+}
+
+// Renvoie un cycle du graphe (premier sommet répété à la fin), ou None si le graphe est acyclique
+// Une boucle (arc d'un sommet vers lui-même) est un cycle [s, s].
+pub fn trouve_cycle<G, S, A>(graphe: &G) -> Option<Vec<S>>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    parcours_postfixe(graphe).err()
+}
+
+// Tri topologique par l'algorithme de Kahn: on retire un à un les sommets sans arc entrant
+// https://fr.wikipedia.org/wiki/Tri_topologique
+// Sortie: les sommets, chacun avant tous ses voisins (à égalité, dans l'ordre de liste_sommets)
+// Erreur ErreurGraphe::Cycle si le graphe a un cycle (il n'y a alors pas d'ordre topologique)
+// Les arcs vers des sommets absents de liste_sommets sont ignorés (comme dans floyd_warshall)
+// Complexité: O(V + E)
+pub fn tri_topologique_kahn<G, S, A>(graphe: &G) -> Result<Vec<S>, ErreurGraphe<S, A>>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    let sommets: Vec<S> = graphe.liste_sommets();
+
+    // Nombre d'arcs entrants de chaque sommet
+    let mut degres_entrants: HashMap<S, usize> =
+        sommets.iter().map(|sommet| (sommet.clone(), 0)).collect();
+    for sommet in sommets.iter() {
+        for (voisin, _dist) in graphe.liste_voisins_et_distances(sommet) {
+            if let Some(degre) = degres_entrants.get_mut(&voisin) {
+                *degre += 1;
+            }
+        }
+    }
+
+    let mut file: VecDeque<S> = sommets
+        .iter()
+        .filter(|sommet| degres_entrants[*sommet] == 0)
+        .cloned()
+        .collect();
+    let mut ordre: Vec<S> = Vec::new();
+    while let Some(sommet) = file.pop_front() {
+        for (voisin, _dist) in graphe.liste_voisins_et_distances(&sommet) {
+            let degre = match (degres_entrants.get_mut(&voisin)) {
+                Some(degre) => degre,
+                None => continue,
+            };
+            *degre -= 1;
+            if (*degre == 0) {
+                file.push_back(voisin);
+            }
+        }
+        ordre.push(sommet);
+    }
+
+    // Les sommets restants ont tous un arc entrant: ils contiennent un cycle
+    if (ordre.len() < degres_entrants.len()) {
+        let cycle = trouve_cycle(graphe).expect("Cycle introuvable.");
+        return Err(ErreurGraphe::Cycle(cycle));
+    }
+    Ok(ordre)
+}
+
+// Tri topologique par parcours en profondeur: ordre postfixe inversé
+// Mêmes sorties que tri_topologique_kahn (l'ordre obtenu peut être différent, les deux étant valides)
+// Complexité: O(V + E)
+pub fn tri_topologique_profondeur<G, S, A>(graphe: &G) -> Result<Vec<S>, ErreurGraphe<S, A>>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    let mut ordre: Vec<S> = parcours_postfixe(graphe).map_err(ErreurGraphe::Cycle)?;
+    ordre.reverse();
+    Ok(ordre)
+}
+
+// Etat de l'algorithme de Tarjan
+struct Tarjan<S, A> {
+    // Numéro de visite de chaque sommet, et plus petit numéro accessible depuis son sous-arbre
+    // (par des arcs vers des sommets encore dans la pile de Tarjan)
+    numeros: HashMap<S, usize>,
+    numeros_min: HashMap<S, usize>,
+    // Pile des sommets visités dont la composante n'est pas encore connue
+    pile_tarjan: Vec<S>,
+    sur_pile_tarjan: HashSet<S>,
+    // Pile d'appels du parcours en profondeur: sommets en cours, avec leurs voisins restant à parcourir
+    pile: Vec<(S, std::vec::IntoIter<(S, A)>)>,
+}
+
+impl<S, A> Tarjan<S, A>
+where
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    fn visite<G: Voisins<S, A>>(&mut self, graphe: &G, sommet: S) {
+        let numero = self.numeros.len();
+        self.numeros.insert(sommet.clone(), numero);
+        self.numeros_min.insert(sommet.clone(), numero);
+        self.pile_tarjan.push(sommet.clone());
+        self.sur_pile_tarjan.insert(sommet.clone());
+        let voisins = graphe.liste_voisins_et_distances(&sommet).into_iter();
+        self.pile.push((sommet, voisins));
+    }
+
+    fn abaisse_numero_min(&mut self, sommet: &S, numero: usize) {
+        let numero_min = self
+            .numeros_min
+            .get_mut(sommet)
+            .expect("Sommet non visité.");
+        *numero_min = (*numero_min).min(numero);
+    }
+}
+
+// Composantes fortement connexes, par l'algorithme de Tarjan
+// https://fr.wikipedia.org/wiki/Algorithme_de_Tarjan
+// Deux sommets sont dans la même composante s'il existe un chemin de l'un à l'autre, et réciproquement.
+// Sortie: les composantes, dans l'ordre topologique inverse du graphe des composantes
+// (une composante n'a d'arc que vers les composantes qui la précèdent dans la liste)
+// Complexité: O(V + E)
+pub fn composantes_fortement_connexes<G, S, A>(graphe: &G) -> Vec<Vec<S>>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    let mut tarjan: Tarjan<S, A> = Tarjan {
+        numeros: HashMap::new(),
+        numeros_min: HashMap::new(),
+        pile_tarjan: Vec::new(),
+        sur_pile_tarjan: HashSet::new(),
+        pile: Vec::new(),
+    };
+    let mut composantes: Vec<Vec<S>> = Vec::new();
+
+    for racine in graphe.liste_sommets() {
+        if (tarjan.numeros.contains_key(&racine)) {
+            continue;
+        }
+        tarjan.visite(graphe, racine);
+
+        while let Some((sommet, voisins)) = tarjan.pile.last_mut() {
+            let sommet = sommet.clone();
+            match (voisins.next()) {
+                Some((voisin, _dist)) => {
+                    if (!tarjan.numeros.contains_key(&voisin)) {
+                        tarjan.visite(graphe, voisin);
+                    } else if (tarjan.sur_pile_tarjan.contains(&voisin)) {
+                        let numero_voisin = tarjan.numeros[&voisin];
+                        tarjan.abaisse_numero_min(&sommet, numero_voisin);
+                    }
+                }
+                None => {
+                    tarjan.pile.pop();
+                    let numero_min_sommet = tarjan.numeros_min[&sommet];
+                    if let Some((parent, _)) = tarjan.pile.last() {
+                        let parent = parent.clone();
+                        tarjan.abaisse_numero_min(&parent, numero_min_sommet);
+                    }
+                    // Racine d'une composante: elle est formée des sommets empilés depuis
+                    if (numero_min_sommet == tarjan.numeros[&sommet]) {
+                        let mut composante: Vec<S> = Vec::new();
+                        while let Some(sommet_composante) = tarjan.pile_tarjan.pop() {
+                            tarjan.sur_pile_tarjan.remove(&sommet_composante);
+                            let fin = (sommet_composante == sommet);
+                            composante.push(sommet_composante);
+                            if (fin) {
+                                break;
+                            }
+                        }
+                        composantes.push(composante);
+                    }
+                }
+            }
+        }
+    }
+
+    composantes
+}
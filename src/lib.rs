@@ -0,0 +1,43 @@
+// Bibliothèque 'algorithmes_en_rust'
+// Réunit les modules d'algorithmes, afin de pouvoir les utiliser depuis d'autres crates:
+//   [dependencies]
+//   algorithmes-en-rust = { git = "https://github.com/v-espitalier/algorithmes-en-rust" }
+// puis par ex: use algorithmes_en_rust::graphes::{resoud_dijstra, Labyrinthe};
+// Le binaire (main.rs) se contente d'appeler les fonctions de cette bibliothèque.
+
+// Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
+#![allow(unused_parens)]
+
+pub mod arbres_couvrants;
+pub mod chemins_toutes_paires;
+pub mod classiques;
+pub mod cli;
+pub mod conversions_hexa_bin_dec;
+pub mod dessin_graphes;
+pub mod divers;
+pub mod erreurs;
+pub mod fichiers;
+pub mod flots;
+pub mod fractales;
+pub mod generateur_labyrinthes;
+pub mod graphe_pondere;
+pub mod graphes;
+pub mod graphes_orientes;
+pub mod parcours_graphes;
+pub mod probabilites;
+pub mod rationnels;
+pub mod tri;
+pub mod tri_sans_comparaison;
+pub mod tri_variantes;
+
+// Ré-export des principaux types, pour un accès direct depuis la racine du crate
+pub use classiques::HanoiGame;
+pub use erreurs::Erreur;
+pub use fractales::{Cercle, Ligne, Rectangle, Texte, Vectorisable};
+pub use graphe_pondere::GraphePondere;
+pub use graphes::{Labyrinthe, Position, Voisins};
+pub use probabilites::RngMinstd;
+pub use rationnels::Rationnels;
+
+#[cfg(test)]
+mod tests;
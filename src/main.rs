@@ -1,28 +1,406 @@
 // Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
 #![allow(unused_parens)]
 
-// Les implémentations des algorithmes de tri sont dans des fichiers
-// séparés algos_tri.rs et algos_tr_variant.rs
-// On inclut ces 'module'
-mod classiques;
-mod conversions_hexa_bin_dec;
-mod divers;
-mod fichiers;
-mod fractales;
-mod graphes;
-mod probabilites;
-mod rationnels;
-mod tri;
-mod tri_variantes;
-
-#[cfg(test)]
-mod tests;
-
+// Les implémentations des algorithmes sont dans la bibliothèque du crate (voir lib.rs)
+// Ce binaire ne fait qu'appeler les fonctions des différents modules
+use algorithmes_en_rust::{
+    classiques, cli, conversions_hexa_bin_dec, dessin_graphes, divers, fichiers, fractales,
+    generateur_labyrinthes, graphes, probabilites, rationnels, tri, tri_sans_comparaison,
+    tri_variantes, GraphePondere, Position,
+};
+
+use std::collections::HashMap;
+use std::process::ExitCode;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use classiques::resoud_tours_de_hanoi;
-
-use crate::rationnels::Rationnels;
+use cli::{Analyse, ArgumentsCommande, SpecCommande, SpecOption};
+
+use rationnels::Rationnels;
+
+const NOM_BINAIRE: &str = "algos";
+
+// Options communes aux commandes qui génèrent un tableau aléatoire
+const OPTION_N: SpecOption = SpecOption {
+    nom: "n",
+    avec_valeur: true,
+    aide: "Taille du tableau généré (défaut: 13)",
+};
+const OPTION_SEED: SpecOption = SpecOption {
+    nom: "seed",
+    avec_valeur: true,
+    aide: "Seed (non nulle) du générateur aléatoire MINSTD (défaut: 1234)",
+};
+
+// Liste des commandes disponibles
+// Pour ajouter une commande: la déclarer ici, puis l'aiguiller dans execute_commande()
+const COMMANDES: &[SpecCommande] = &[
+    SpecCommande {
+        nom: "classiques",
+        resume: "Factorielle, pgcd (Rust et assembleur), Fibonacci (itératif et récursif)",
+        usage: "",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 0,
+        options: &[],
+    },
+    SpecCommande {
+        nom: "hanoi",
+        resume: "Résolution du problème des tours de Hanoï",
+        usage: "<nombre de disques>",
+        nb_positionnels_min: 1,
+        nb_positionnels_max: 1,
+        options: &[],
+    },
+    SpecCommande {
+        nom: "recherche",
+        resume: "Recherche linéaire (ou dichotomique) d'une valeur dans un tableau mélangé",
+        usage: "<valeur> [--n N] [--seed S] [--dichotomique]",
+        nb_positionnels_min: 1,
+        nb_positionnels_max: 1,
+        options: &[
+            OPTION_N,
+            OPTION_SEED,
+            SpecOption {
+                nom: "dichotomique",
+                avec_valeur: false,
+                aide: "Trie le tableau, puis effectue une recherche dichotomique",
+            },
+        ],
+    },
+    SpecCommande {
+        nom: "tri",
+        resume: "Tri d'un tableau d'entiers (lu dans un fichier, ou généré et mélangé)",
+        usage: "[--algo ALGO] [--partition P] [--pivot P] [--input fichier] [--output fichier] [--n N] [--seed S] [--affiche]",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 0,
+        options: &[
+            SpecOption {
+                nom: "algo",
+                avec_valeur: true,
+                aide: "insertion, selection, rapide, fusion, fusion-ameliore, fusion-ascendant, introspectif, timsort ou tas (défaut: tas)",
+            },
+            SpecOption {
+                nom: "partition",
+                avec_valeur: true,
+                aide: "Partition du tri rapide: lomuto, hoare ou trois-voies (défaut: hoare)",
+            },
+            SpecOption {
+                nom: "pivot",
+                avec_valeur: true,
+                aide: "Pivot du tri rapide: mediane (de trois) ou aleatoire (défaut: mediane)",
+            },
+            SpecOption {
+                nom: "input",
+                avec_valeur: true,
+                aide: "Fichier texte contenant les entiers à trier (séparés par des espaces)",
+            },
+            SpecOption {
+                nom: "output",
+                avec_valeur: true,
+                aide: "Fichier texte où écrire le tableau trié (un entier par ligne)",
+            },
+            OPTION_N,
+            OPTION_SEED,
+            SpecOption {
+                nom: "affiche",
+                avec_valeur: false,
+                aide: "Affiche le tableau avant et après le tri",
+            },
+        ],
+    },
+    SpecCommande {
+        nom: "tri-variantes",
+        resume: "Tris génériques (au sens des traits Rust) sur un tableau de chaines",
+        usage: "",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 0,
+        options: &[],
+    },
+    SpecCommande {
+        nom: "tri-bench",
+        resume: "Tris sans comparaison (comptage, base, paquets) comparés au tri par tas",
+        usage: "[--n N] [--seed S]",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 0,
+        options: &[
+            SpecOption {
+                nom: "n",
+                avec_valeur: true,
+                aide: "Taille des tableaux générés (défaut: 1000000)",
+            },
+            OPTION_SEED,
+        ],
+    },
+    SpecCommande {
+        nom: "tri-parallele",
+        resume: "Tri rapide et tri fusion parallèles (threads): accélération par rapport aux versions séquentielles",
+        usage: "[--n N] [--threads N] [--seuil S] [--seed S]",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 0,
+        options: &[
+            SpecOption {
+                nom: "n",
+                avec_valeur: true,
+                aide: "Taille du tableau généré (défaut: 10000000)",
+            },
+            SpecOption {
+                nom: "threads",
+                avec_valeur: true,
+                aide: "Nombre de threads (défaut: nombre de coeurs disponibles)",
+            },
+            SpecOption {
+                nom: "seuil",
+                avec_valeur: true,
+                aide: "Taille en dessous de laquelle un sous-tableau est trié sans nouveau thread (défaut: 10000)",
+            },
+            OPTION_SEED,
+        ],
+    },
+    SpecCommande {
+        nom: "probas",
+        resume: "Lois normales par la méthode de Box-Muller: moyenne et variance",
+        usage: "[--n N] [--seed S]",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 0,
+        options: &[
+            SpecOption {
+                nom: "n",
+                avec_valeur: true,
+                aide: "Nombre de tirages (défaut: 10000)",
+            },
+            OPTION_SEED,
+        ],
+    },
+    SpecCommande {
+        nom: "dames",
+        resume: "Résolution du problème des 8 dames",
+        usage: "",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 0,
+        options: &[],
+    },
+    SpecCommande {
+        nom: "premiers",
+        resume: "Recherche des nombres premiers dans [min, max[ (avec option multithreading)",
+        usage: "<min> <max> [--threads N] [--compare] [--affiche]",
+        nb_positionnels_min: 2,
+        nb_positionnels_max: 2,
+        options: &[
+            SpecOption {
+                nom: "threads",
+                avec_valeur: true,
+                aide: "Nombre de threads (défaut: 1 -> sans multithreading)",
+            },
+            SpecOption {
+                nom: "compare",
+                avec_valeur: false,
+                aide: "Compare les durées avec et sans multithreading",
+            },
+            SpecOption {
+                nom: "affiche",
+                avec_valeur: false,
+                aide: "Affiche les nombres premiers trouvés",
+            },
+        ],
+    },
+    SpecCommande {
+        nom: "syracuse",
+        resume: "Temps de vol et altitude maximale des suites de Syracuse",
+        usage: "[n] [--n-max N]",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 1,
+        options: &[SpecOption {
+            nom: "n-max",
+            avec_valeur: true,
+            aide: "Calcule le temps de vol maximal des suites de départ <= N",
+        }],
+    },
+    SpecCommande {
+        nom: "rationnels",
+        resume: "Arithmétique sur les nombres rationnels",
+        usage: "",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 0,
+        options: &[],
+    },
+    SpecCommande {
+        nom: "fichiers",
+        resume: "Lecture / écriture / listing de fichiers (dans le dossier divers/)",
+        usage: "",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 0,
+        options: &[],
+    },
+    SpecCommande {
+        nom: "conversions",
+        resume: "Conversions d'entiers: décimal / hexadécimal / binaire / octal",
+        usage: "",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 0,
+        options: &[],
+    },
+    SpecCommande {
+        nom: "labyrinthe",
+        resume: "Résolution de labyrinthe(s) par Dijkstra, parcours en largeur, A* ou recherche gloutonne",
+        usage: "[plan.txt [solution.txt]] [--dossier dossier] [--algo A] [--heuristique H] [--terrain] [--diagonales] [--images]",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 2,
+        options: &[
+            SpecOption {
+                nom: "dossier",
+                avec_valeur: true,
+                aide: "Résout tous les plans du dossier (défaut, sans plan: divers/labyrinthes)",
+            },
+            SpecOption {
+                nom: "algo",
+                avec_valeur: true,
+                aide: "dijkstra (défaut), largeur, a-etoile ou glouton",
+            },
+            SpecOption {
+                nom: "heuristique",
+                avec_valeur: true,
+                aide: "Pour a-etoile et glouton: manhattan (défaut), euclidienne ou octile",
+            },
+            SpecOption {
+                nom: "terrain",
+                avec_valeur: false,
+                aide: "Carte de terrain: chiffres 1-9 = coût de la case, flèches ^ v < > = sens uniques",
+            },
+            SpecOption {
+                nom: "diagonales",
+                avec_valeur: false,
+                aide: "Déplacements dans les 8 directions (coûts x1000, diagonale x1415)",
+            },
+            SpecOption {
+                nom: "images",
+                avec_valeur: false,
+                aide: "Dessine aussi chaque solution en SVG et BMP (à côté du fichier solution)",
+            },
+        ],
+    },
+    SpecCommande {
+        nom: "genere-labyrinthe",
+        resume: "Génération d'un labyrinthe parfait, au format des plans de divers/labyrinthes",
+        usage: "[sortie.txt] [--hauteur H] [--largeur L] [--algo A] [--seed S]",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 1,
+        options: &[
+            SpecOption {
+                nom: "hauteur",
+                avec_valeur: true,
+                aide: "Nombre de cases en hauteur (défaut: 10)",
+            },
+            SpecOption {
+                nom: "largeur",
+                avec_valeur: true,
+                aide: "Nombre de cases en largeur (défaut: 20)",
+            },
+            SpecOption {
+                nom: "algo",
+                avec_valeur: true,
+                aide: "retour-sur-trace (défaut), kruskal, prim ou wilson",
+            },
+            OPTION_SEED,
+        ],
+    },
+    SpecCommande {
+        nom: "dijkstra-bench",
+        resume: "Mesure de performance de Dijkstra sur de grands labyrinthes aléatoires",
+        usage: "[--tailles T1,T2,..] [--proportion-murs P] [--seed S]",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 0,
+        options: &[
+            SpecOption {
+                nom: "tailles",
+                avec_valeur: true,
+                aide: "Côtés des labyrinthes carrés générés (défaut: 100,200,400)",
+            },
+            SpecOption {
+                nom: "proportion-murs",
+                avec_valeur: true,
+                aide: "Proportion de murs dans le labyrinthe, entre 0 et 1 (défaut: 0.2)",
+            },
+            OPTION_SEED,
+        ],
+    },
+    SpecCommande {
+        nom: "graphe",
+        resume: "Plus court chemin (Dijkstra ou Bellman-Ford) dans un graphe pondéré lu depuis un fichier",
+        usage: "<graphe.txt> <source> <destination> [--dimacs] [--oriente] [--bellman-ford] [--dot f.dot] [--svg f.svg]",
+        nb_positionnels_min: 3,
+        nb_positionnels_max: 3,
+        options: &[
+            SpecOption {
+                nom: "dimacs",
+                avec_valeur: false,
+                aide: "Fichier au format DIMACS (.gr; sommets numérotés à partir de 1)",
+            },
+            SpecOption {
+                nom: "oriente",
+                avec_valeur: false,
+                aide: "Graphe orienté (défaut: non orienté pour une liste d'arêtes, orienté en DIMACS)",
+            },
+            SpecOption {
+                nom: "bellman-ford",
+                avec_valeur: false,
+                aide: "Utilise Bellman-Ford (poids négatifs acceptés, détection des cycles négatifs)",
+            },
+            SpecOption {
+                nom: "dot",
+                avec_valeur: true,
+                aide: "Exporte le graphe et le chemin trouvé au format DOT de Graphviz",
+            },
+            SpecOption {
+                nom: "svg",
+                avec_valeur: true,
+                aide: "Dessine le graphe et le chemin trouvé en SVG (disposition par forces)",
+            },
+        ],
+    },
+    SpecCommande {
+        nom: "koch",
+        resume: "Flocon de Koch, exporté en SVG",
+        usage: "[sortie.svg] [--n-iter N] [--hauteur H] [--largeur L]",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 1,
+        options: &[
+            SpecOption {
+                nom: "n-iter",
+                avec_valeur: true,
+                aide: "Nombre d'itérations (défaut: 4)",
+            },
+            SpecOption {
+                nom: "hauteur",
+                avec_valeur: true,
+                aide: "Hauteur de l'image (défaut: 1024)",
+            },
+            SpecOption {
+                nom: "largeur",
+                avec_valeur: true,
+                aide: "Largeur de l'image (défaut: 1920)",
+            },
+        ],
+    },
+    SpecCommande {
+        nom: "fractale",
+        resume: "Ensemble de Julia de paramètre c = cx + i.cy, exporté en BMP",
+        usage: "[sortie.bmp] [--cx X] [--cy Y]",
+        nb_positionnels_min: 0,
+        nb_positionnels_max: 1,
+        options: &[
+            SpecOption {
+                nom: "cx",
+                avec_valeur: true,
+                aide: "Partie réelle de c (défaut: 0.3)",
+            },
+            SpecOption {
+                nom: "cy",
+                avec_valeur: true,
+                aide: "Partie imaginaire de c (défaut: 0.5)",
+            },
+        ],
+    },
+];
 
 // Connaitre le temps en secondes depuis l'epoch
 fn get_curr_time_epoch() -> f64 {
@@ -33,218 +411,464 @@ fn get_curr_time_epoch() -> f64 {
         / 1000.
 }
 
-fn main() {
-    println!("Hello, world!");
-
-    let b_test_classiques = true;
-    let b_test_recherche_tableau_et_tris = false;
-    let b_test_tris_variants = false;
-    let b_test_probas = false;
-    let b_test_algos_divers = false;
-    let b_test_rationnels = false;
-    let b_test_fichiers = false;
-    let b_test_conversions_entiers = false;
-    let b_test_graphes = false;
-    let b_test_fractales = false;
-
-    // Test des fonctions 'mathématiques': Factorielle, pgcd, fibonacci_interatif, fibonacci_recursif
-    if (b_test_classiques) {
-        println!();
-        let n: u64 = 5;
-        println!("Factorielle({}) = {}", n, classiques::factorielle(n));
-
-        //let a: u64 = 48;
-        //let b: u64 = 42;
-        let a: u64 = 90;
-        let b: u64 = 28;
-        println!("pgcd({}, {}) = {}\n", a, b, classiques::pgcd(a, b));
-        println!("pgcd_asm({}, {}) = {}\n", a, b, divers::pgcd_asm(a, b));
-
-        let n = 5;
-        //let n = 100;   Pour comparer les temps de calcul des 2 implémentations de Fibonacci
-        for i in 0..n {
-            println!(
-                "Fibonacci_iteratif({}) = {}",
-                i,
-                classiques::fibonacci_iteratif(i)
-            );
-            println!(
-                "Fibonacci_recursif({}) = {}",
-                i,
-                classiques::fibonacci_recursif(i)
-            );
-        }
+// Lit la seed dans les arguments, et vérifie qu'elle est non nulle (contrainte de RngMinstd)
+fn lit_seed(args: &ArgumentsCommande) -> Result<u32, String> {
+    let seed: u32 = args.option_ou("seed", 1234)?;
+    if (seed == 0) {
+        return Err("La seed doit être différente de zéro.".to_string());
+    }
+    Ok(seed)
+}
 
-        for n in 1..6 {
-            resoud_tours_de_hanoi(n);
-            println!("\n");
-        }
+// Lit la taille du tableau à générer (option --n), et vérifie qu'elle est strictement positive
+fn lit_taille_tableau(args: &ArgumentsCommande) -> Result<i32, String> {
+    let n: i32 = args.option_ou("n", 13)?;
+    if (n <= 0) {
+        return Err("La taille du tableau (--n) doit être strictement positive.".to_string());
     }
+    Ok(n)
+}
 
-    if (b_test_recherche_tableau_et_tris) {
-        println!();
+// Tableau 0..n, mélangé par l'algorithme de Fisher Yates
+fn genere_tableau_melange(n: i32, seed: u32) -> Vec<i32> {
+    let mut mon_tableau: Vec<i32> = Vec::from_iter(0..n);
+    probabilites::fisher_yates_shuffle(mon_tableau.as_mut_slice(), seed);
+    mon_tableau
+}
 
-        // Tester le générateur aléatoire (MINSTD)
-        if (false) {
-            let seed: u32 = 1234;
-            let mut rng: probabilites::RngMinstd = probabilites::RngMinstd::new(seed);
-            println!("PRNG: {}", rng.gen());
-        }
+// Test des fonctions 'mathématiques': Factorielle, pgcd, fibonacci_interatif, fibonacci_recursif
+fn commande_classiques() -> Result<(), String> {
+    println!();
+    let n: u64 = 5;
+    println!("Factorielle({}) = {}", n, classiques::factorielle(n));
+
+    //let a: u64 = 48;
+    //let b: u64 = 42;
+    let a: u64 = 90;
+    let b: u64 = 28;
+    println!("pgcd({}, {}) = {}\n", a, b, classiques::pgcd(a, b));
+    println!("pgcd_asm({}, {}) = {}\n", a, b, divers::pgcd_asm(a, b));
+
+    let n = 5;
+    //let n = 100;   Pour comparer les temps de calcul des 2 implémentations de Fibonacci
+    for i in 0..n {
+        println!(
+            "Fibonacci_iteratif({}) = {}",
+            i,
+            classiques::fibonacci_iteratif(i)
+        );
+        println!(
+            "Fibonacci_recursif({}) = {}",
+            i,
+            classiques::fibonacci_recursif(i)
+        );
+    }
+
+    Ok(())
+}
 
-        let seed: u32 = 1234;
+fn commande_hanoi(args: &ArgumentsCommande) -> Result<(), String> {
+    let n: u32 = args.positionnel(0, "nombre de disques")?;
+    // L'état des tours est affiché après chacun des (2^n - 1) déplacements
+    if (n > 20) {
+        return Err("Le nombre de disques doit être inférieur ou égal à 20.".to_string());
+    }
+    resoud_tours_de_hanoi(n);
+    Ok(())
+}
 
-        let n = 13;
-        //let n = 40000;
+fn commande_recherche(args: &ArgumentsCommande) -> Result<(), String> {
+    let p: i32 = args.positionnel(0, "valeur")?;
+    let n: i32 = lit_taille_tableau(args)?;
+    let seed: u32 = lit_seed(args)?;
 
-        //let mut mon_tableau: Vec<i32> = vec![1, 2, 3, 4, 5];
-        //let mut mon_tableau: Vec<i32> = vec![5, 4, 3, 2, 1];
-        let mut mon_tableau: Vec<i32> = Vec::from_iter(0..n);
-        //let mut mon_tableau: Vec<i32> = Vec::from_iter((0..n).rev());
+    let mut mon_tableau: Vec<i32> = genere_tableau_melange(n, seed);
+    let mon_tableau2: &mut [i32] = mon_tableau.as_mut_slice();
+    println!("\ntableau mélangé: \n {:?}\n", &mon_tableau2);
 
-        let mon_tableau2: &mut [i32] = mon_tableau.as_mut_slice();
+    let index_opt: Option<usize> = if (args.drapeau("dichotomique")) {
+        tri::tri_par_tas_generique(mon_tableau2);
+        println!("tableau trié: \n{:?}", &mon_tableau2);
+        classiques::recherche_dichotomique(mon_tableau2, p, None, None)
+    } else {
+        classiques::recherche_lineaire(mon_tableau2, p)
+    };
+
+    match (index_opt) {
+        Some(index) => println!("Valeur {} trouvée: index {}", p, index),
+        None => println!("Valeur {} non trouvée", p),
+    }
+    Ok(())
+}
 
-        println!("\ntableau départ: \n {:?}", &mon_tableau2);
-        probabilites::fisher_yates_shuffle(mon_tableau2, seed);
-        println!("\ntableau mélangé: \n {:?}\n", &mon_tableau2);
+fn lit_parametres_tri_rapide(args: &ArgumentsCommande) -> Result<tri::ParametresTriRapide, String> {
+    let partition_str: String = args.option_ou("partition", "hoare".to_string())?;
+    let partition = match (partition_str.as_str()) {
+        "lomuto" => tri::Partition::Lomuto,
+        "hoare" => tri::Partition::Hoare,
+        "trois-voies" => tri::Partition::TroisVoies,
+        _ => return Err(format!("Partition inconnue: {}", partition_str)),
+    };
+    let pivot_str: String = args.option_ou("pivot", "mediane".to_string())?;
+    let pivot = match (pivot_str.as_str()) {
+        "mediane" => tri::ChoixPivot::MedianeDeTrois,
+        "aleatoire" => tri::ChoixPivot::Aleatoire {
+            seed: lit_seed(args)?,
+        },
+        _ => return Err(format!("Choix de pivot inconnu: {}", pivot_str)),
+    };
+    Ok(tri::ParametresTriRapide {
+        partition,
+        pivot,
+        ..Default::default()
+    })
+}
 
-        let p: i32 = 8;
-        println!(
-            "Recherche lineaire de la valeur {}: index {} \n",
-            p,
-            classiques::recherche_lineaire(mon_tableau2, p).unwrap()
-        );
-        let p: i32 = 12;
-        println!(
-            "Recherche lineaire générique de la valeur {}: index {} \n",
-            p,
-            classiques::recherche_lineaire_generique(mon_tableau2, p).unwrap()
-        );
-        if (false) {
-            println!(
-                "Recherche dichotomique de la valeur {}: index {}",
-                p,
-                classiques::recherche_dichotomique(mon_tableau2, p, None, None).unwrap()
-            );
+fn commande_tri(args: &ArgumentsCommande) -> Result<(), String> {
+    let algo: String = args.option_ou("algo", "tas".to_string())?;
+
+    let mut mon_tableau: Vec<i32> = match (args.option::<String>("input")?) {
+        Some(f_input) => {
+            let contenu: String = fichiers::lire_fichier_texte(&f_input)?;
+            let mut valeurs: Vec<i32> = Vec::new();
+            for mot in contenu.split_whitespace() {
+                valeurs.push(
+                    mot.parse::<i32>()
+                        .map_err(|_| format!("Entier invalide dans {}: '{}'", f_input, mot))?,
+                );
+            }
+            valeurs
         }
+        None => {
+            let n: i32 = lit_taille_tableau(args)?;
+            genere_tableau_melange(n, lit_seed(args)?)
+        }
+    };
+    let mon_tableau2: &mut [i32] = mon_tableau.as_mut_slice();
 
-        //algos_tri::tri_par_insertion(mon_tableau2);
-        //algos_tri::tri_par_selection(mon_tableau2);
+    if (args.drapeau("affiche")) {
+        println!("\ntableau départ: \n {:?}", &mon_tableau2);
+    }
 
-        //algos_tri::tri_rapide(mon_tableau2);
-        //algos_tri::tri_fusion(mon_tableau2);
-        tri::tri_par_tas_generique(mon_tableau2);
+    let chrono_start = get_curr_time_epoch();
+    match (algo.as_str()) {
+        "insertion" => tri::tri_par_insertion(mon_tableau2),
+        "selection" => tri::tri_par_selection(mon_tableau2),
+        "rapide" => {
+            tri::tri_rapide_parametre(mon_tableau2, &lit_parametres_tri_rapide(args)?, i32::cmp)
+        }
+        "fusion" => tri::tri_fusion(mon_tableau2),
+        "fusion-ameliore" => tri_variantes::tri_fusion_ameliore(mon_tableau2),
+        "fusion-ascendant" => tri_variantes::tri_fusion_ascendant(mon_tableau2),
+        "introspectif" => tri::tri_introspectif(mon_tableau2),
+        "timsort" => tri_variantes::tri_timsort(mon_tableau2),
+        "tas" => tri::tri_par_tas_generique(mon_tableau2),
+        _ => return Err(format!("Algorithme de tri inconnu: {}", algo)),
+    }
+    let chrono_end = get_curr_time_epoch();
 
+    if (args.drapeau("affiche")) {
         println!("tableau trié: \n{:?}", &mon_tableau2);
+    }
+    if (!mon_tableau2.is_empty()) {
         assert!(
             tri::verif_tableau_croissant(mon_tableau2),
             "Erreur: le tableau n'est pas correctement trié."
         );
     }
+    println!(
+        "{} élément(s) triés (tri {}) en {} s",
+        mon_tableau2.len(),
+        algo,
+        chrono_end - chrono_start
+    );
+
+    if let Some(f_output) = args.option::<String>("output")? {
+        let lignes: Vec<String> = mon_tableau2.iter().map(|v| v.to_string()).collect();
+        fichiers::ecrire_fichier_texte_lignes(&f_output, &lignes)?;
+    }
+    Ok(())
+}
 
-    if (b_test_tris_variants) {
-        println!();
-        // Tableau de données string
-        let mut mon_tableau_gen: Vec<String> = vec![
-            "rust".to_string(),
-            "go".to_string(),
-            "shell".to_string(),
-            "ruby".to_string(),
-            "python".to_string(),
-        ];
-        let mon_tableau_gen2: &mut [String] = mon_tableau_gen.as_mut_slice();
-
-        /*
-        // Tableau d'entiers
-        let seed: u32 = 1234;
-        let n = 17;
-        let mut mon_tableau_gen: Vec<i32> = Vec::from_iter((0..n));
-        let mon_tableau_gen2: &mut [i32] = mon_tableau_gen.as_mut_slice();
-        probabilites::fisher_yates_shuffle(mon_tableau_gen2, seed);
-        */
-
-        // Tableau de flottants
-        // Ordre pas total sur les flottants (car valeur NaN possible)
-        // let mut mon_tableau_gen: Vec<f64> = vec![3.1415, 1.4142, 2.718, 1.732, 6.022, -273.15];
-        // let mon_tableau_gen2: &mut [f64] = mon_tableau_gen.as_mut_slice();
-
-        println!("\ntableau départ: \n {:?}", &mon_tableau_gen2);
-
-        tri_variantes::tri_par_insertion_generique(mon_tableau_gen2);
-
-        // Tri fusion: Implémenté uniquement sur les entiers 'i32' (pas générique)
-        //tri_variantes::tri_fusion_ameliore(mon_tableau_gen2, None, None, None);
-
-        // Pour tester le tri par selection, qui est implémenté en 'indirect',
-        // et ne modifie pas directement le tableau.
-        // => nécessite d'appliquer la permutation à postériori.
-        if (false) {
-            let permutation = tri_variantes::tri_par_selection_indirect_generique(mon_tableau_gen2);
-            let mut mon_tableau_gen2_vec =
-                tri_variantes::permute_copie_tableau(mon_tableau_gen2, &permutation);
-            mon_tableau_gen2.clone_from_slice(mon_tableau_gen2_vec.as_mut_slice());
-            println!("Permutation: {:?}", permutation);
-        }
+fn commande_tri_variantes() -> Result<(), String> {
+    println!();
+    // Tableau de données string
+    let mut mon_tableau_gen: Vec<String> = vec![
+        "rust".to_string(),
+        "go".to_string(),
+        "shell".to_string(),
+        "ruby".to_string(),
+        "python".to_string(),
+    ];
+    let mon_tableau_gen2: &mut [String] = mon_tableau_gen.as_mut_slice();
+
+    /*
+    // Tableau d'entiers
+    let seed: u32 = 1234;
+    let n = 17;
+    let mut mon_tableau_gen: Vec<i32> = Vec::from_iter((0..n));
+    let mon_tableau_gen2: &mut [i32] = mon_tableau_gen.as_mut_slice();
+    probabilites::fisher_yates_shuffle(mon_tableau_gen2, seed);
+    */
+
+    // Tableau de flottants
+    // Ordre pas total sur les flottants (car valeur NaN possible)
+    // let mut mon_tableau_gen: Vec<f64> = vec![3.1415, 1.4142, 2.718, 1.732, 6.022, -273.15];
+    // let mon_tableau_gen2: &mut [f64] = mon_tableau_gen.as_mut_slice();
+
+    println!("\ntableau départ: \n {:?}", &mon_tableau_gen2);
+
+    tri_variantes::tri_par_insertion_generique(mon_tableau_gen2);
+
+    // Tri fusion (générique également)
+    //tri_variantes::tri_fusion_ameliore(mon_tableau_gen2);
+
+    // Pour tester le tri par selection, qui est implémenté en 'indirect',
+    // et ne modifie pas directement le tableau.
+    // => nécessite d'appliquer la permutation à postériori.
+    if (false) {
+        let permutation = tri_variantes::tri_par_selection_indirect_generique(mon_tableau_gen2);
+        let mut mon_tableau_gen2_vec =
+            tri_variantes::permute_copie_tableau(mon_tableau_gen2, &permutation);
+        mon_tableau_gen2.clone_from_slice(mon_tableau_gen2_vec.as_mut_slice());
+        println!("Permutation: {:?}", permutation);
+    }
 
-        println!("tableau trié: \n{:?}", &mon_tableau_gen2);
-        assert!(
-            tri::verif_tableau_croissant(mon_tableau_gen2),
-            "Erreur: le tableau n'est pas correctement trié."
+    println!("tableau trié: \n{:?}", &mon_tableau_gen2);
+    assert!(
+        tri::verif_tableau_croissant(mon_tableau_gen2),
+        "Erreur: le tableau n'est pas correctement trié."
+    );
+
+    // Tri selon une clé: longueur décroissante (tri fusion stable: l'ordre alphabétique est gardé à égalité)
+    tri_variantes::tri_fusion_par_cle(mon_tableau_gen2, |mot| std::cmp::Reverse(mot.len()));
+    println!(
+        "tableau trié par longueur décroissante: \n{:?}",
+        &mon_tableau_gen2
+    );
+
+    Ok(())
+}
+
+// Trie une copie du tableau, affiche la durée, et renvoie le tableau trié
+fn mesure_tri<T: Clone>(
+    donnees: &str,
+    algo: &str,
+    mon_tableau: &[T],
+    tri: impl Fn(&mut [T]),
+) -> Vec<T> {
+    let mut copie: Vec<T> = mon_tableau.to_vec();
+    let chrono_start = get_curr_time_epoch();
+    tri(&mut copie);
+    println!(
+        "{} | {} | {}",
+        donnees,
+        algo,
+        get_curr_time_epoch() - chrono_start
+    );
+    copie
+}
+
+// Durées des tris sans comparaison, et du tri par tas sur les mêmes données
+fn commande_tri_bench(args: &ArgumentsCommande) -> Result<(), String> {
+    let n: usize = args.option_ou("n", 1000000)?;
+    let seed: u32 = lit_seed(args)?;
+    let mut rng = probabilites::RngMinstd::new(seed);
+
+    let non_signes: Vec<u32> = (0..n).map(|_| rng.gen()).collect();
+    let signes: Vec<i64> = (0..n)
+        .map(|_| ((rng.gen() as i64) << 32) - ((rng.gen() as i64) << 31))
+        .collect();
+    let petits: Vec<u8> = (0..n).map(|_| rng.gen_range(0..100) as u8).collect();
+    let mots: Vec<String> = (0..n)
+        .map(|_| {
+            let longueur = rng.gen_range(1..13) as usize;
+            (0..longueur)
+                .map(|_| (b'a' + rng.gen_range(0..26) as u8) as char)
+                .collect()
+        })
+        .collect();
+    let normales: Vec<f64> = probabilites::box_muller(n, seed);
+
+    println!("données | algorithme | durée (s)");
+    let reference = mesure_tri("u32", "tas", &non_signes, tri::tri_par_tas_generique);
+    for (algo, tri_radix) in [
+        (
+            "radix lsd",
+            tri_sans_comparaison::tri_radix_lsd as fn(&mut [u32]),
+        ),
+        ("radix msd", tri_sans_comparaison::tri_radix_msd),
+    ] {
+        assert_eq!(mesure_tri("u32", algo, &non_signes, tri_radix), reference);
+    }
+
+    let reference = mesure_tri("i64", "tas", &signes, tri::tri_par_tas_generique);
+    for (algo, tri_radix) in [
+        (
+            "radix lsd",
+            tri_sans_comparaison::tri_radix_lsd as fn(&mut [i64]),
+        ),
+        ("radix msd", tri_sans_comparaison::tri_radix_msd),
+    ] {
+        assert_eq!(mesure_tri("i64", algo, &signes, tri_radix), reference);
+    }
+
+    let reference = mesure_tri("u8 (0..100)", "tas", &petits, tri::tri_par_tas_generique);
+    let trie = mesure_tri(
+        "u8 (0..100)",
+        "comptage",
+        &petits,
+        tri_sans_comparaison::tri_comptage,
+    );
+    assert_eq!(trie, reference);
+
+    let reference = mesure_tri("chaines", "tas", &mots, tri::tri_par_tas_generique);
+    for (algo, tri_radix) in [
+        (
+            "radix lsd",
+            tri_sans_comparaison::tri_radix_lsd_octets as fn(&mut [String]),
+        ),
+        ("radix msd", tri_sans_comparaison::tri_radix_msd_octets),
+    ] {
+        assert_eq!(mesure_tri("chaines", algo, &mots, tri_radix), reference);
+    }
+
+    // (f64 n'implémente pas Ord, à cause de NaN: tri par tas selon l'ordre total des flottants)
+    let reference = mesure_tri("f64 (loi normale)", "tas", &normales, |t| {
+        tri::tri_par_tas_avec_comparaison(t, f64::total_cmp)
+    });
+    let trie = mesure_tri(
+        "f64 (loi normale)",
+        "paquets",
+        &normales,
+        tri_sans_comparaison::tri_par_paquets,
+    );
+    assert_eq!(trie, reference);
+    Ok(())
+}
+
+// Durées des tris parallèles, comparées à celles des mêmes tris séquentiels
+fn commande_tri_parallele(args: &ArgumentsCommande) -> Result<(), String> {
+    let n: usize = args.option_ou("n", 10000000)?;
+    let parametres_defaut = tri::ParametresTriParallele::default();
+    let parametres = tri::ParametresTriParallele {
+        nb_threads: args.option_ou("threads", parametres_defaut.nb_threads)?,
+        seuil_sequentiel: args.option_ou("seuil", parametres_defaut.seuil_sequentiel)?,
+    };
+    if (parametres.nb_threads == 0) {
+        return Err("Le nombre de threads doit être au moins 1.".to_string());
+    }
+    let mut rng = probabilites::RngMinstd::new(lit_seed(args)?);
+    let mon_tableau: Vec<u32> = (0..n).map(|_| rng.gen()).collect();
+
+    println!(
+        "{} éléments, {} thread(s), seuil séquentiel {}",
+        n, parametres.nb_threads, parametres.seuil_sequentiel
+    );
+    println!("algorithme | durée séquentielle (s) | durée parallèle (s) | accélération");
+    let tri_rapide_parallele = |t: &mut [u32]| tri::tri_rapide_parallele(t, &parametres);
+    let tri_fusion_parallele = |t: &mut [u32]| tri::tri_fusion_parallele(t, &parametres);
+    type TriU32<'a> = &'a dyn Fn(&mut [u32]);
+    let tris: [(&str, TriU32, TriU32); 2] = [
+        ("rapide", &tri::tri_rapide, &tri_rapide_parallele),
+        (
+            "fusion",
+            &tri_variantes::tri_fusion_ameliore,
+            &tri_fusion_parallele,
+        ),
+    ];
+    for (algo, tri_sequentiel, tri_parallele) in tris {
+        let mut trie_sequentiel: Vec<u32> = mon_tableau.clone();
+        let chrono_start = get_curr_time_epoch();
+        tri_sequentiel(&mut trie_sequentiel);
+        let duree_sequentielle = get_curr_time_epoch() - chrono_start;
+
+        let mut trie_parallele: Vec<u32> = mon_tableau.clone();
+        let chrono_start = get_curr_time_epoch();
+        tri_parallele(&mut trie_parallele);
+        let duree_parallele = get_curr_time_epoch() - chrono_start;
+
+        assert_eq!(
+            trie_sequentiel, trie_parallele,
+            "Erreur: les tris séquentiel et parallèle diffèrent."
+        );
+        println!(
+            "{} | {} | {} | {:.2}",
+            algo,
+            duree_sequentielle,
+            duree_parallele,
+            duree_sequentielle / duree_parallele
         );
     }
+    Ok(())
+}
+
+fn commande_probas(args: &ArgumentsCommande) -> Result<(), String> {
+    let n: usize = args.option_ou("n", 10000)?;
+    let seed: u32 = lit_seed(args)?;
+    if (n < 2) {
+        return Err("Il faut au moins 2 tirages pour calculer la variance.".to_string());
+    }
+    let normales: Vec<f64> = probabilites::box_muller(n, seed);
+    let moyenne = probabilites::moyenne(normales.as_slice()).unwrap();
+    let variance = probabilites::variance(normales.as_slice(), None).unwrap();
+    //println!("normales: {:?}", &normales);
+    println!("moyenne, variance, : {} {}", moyenne, variance);
+    Ok(())
+}
+
+fn commande_dames() -> Result<(), String> {
+    println!();
+    let solutions = divers::resoud_probleme_des_8_dames();
+    println!("Nb solutions: {}", solutions.len());
+    //println!("Solutions: {:?}", solutions);
+    let solutions_uniques = divers::calcule_solutions_uniques(&solutions);
+    println!("Nb solutions uniques: {}", solutions_uniques.len());
+    println!("Solutions uniques: {:?}", solutions_uniques);
+    println!(" ");
+    divers::affiche_solutions_probleme_des_8_dames(&solutions_uniques);
+    Ok(())
+}
 
-    if (b_test_probas) {
-        println!();
-        let seed: u32 = 1234;
-        let n: usize = 10000;
-        let normales: Vec<f64> = probabilites::box_muller(n, seed);
-        let moyenne = probabilites::moyenne(normales.as_slice()).unwrap();
-        let variance = probabilites::variance(normales.as_slice(), None).unwrap();
-        //println!("normales: {:?}", &normales);
-        println!("moyenne, variance, : {} {}", moyenne, variance);
-    }
-
-    if (b_test_algos_divers) {
-        println!();
-        let solutions = divers::resoud_probleme_des_8_dames();
-        println!("Nb solutions: {}", solutions.len());
-        //println!("Solutions: {:?}", solutions);
-        let solutions_uniques = divers::calcule_solutions_uniques(&solutions);
-        println!("Nb solutions uniques: {}", solutions_uniques.len());
-        println!("Solutions uniques: {:?}", solutions_uniques);
-        println!(" ");
-        divers::affiche_solutions_probleme_des_8_dames(&solutions_uniques);
-
-        println!(" ");
-
-        // Test des fonctions de recherche de nombres premiers
-        let min_n: usize = 0;
-        //let min_n:usize  = 1000000;
-        //let max_n:usize  = 100;
-        let max_n: usize = 2000000;
-        //let max_n:usize  = 10000000;
-        //let max_n:usize  = 1800000;
+// Test des fonctions de recherche de nombres premiers
+fn commande_premiers(args: &ArgumentsCommande) -> Result<(), String> {
+    let min_n: usize = args.positionnel(0, "min")?;
+    let max_n: usize = args.positionnel(1, "max")?;
+    let n_threads: usize = args.option_ou("threads", 1)?;
+    if (min_n >= max_n) {
+        return Err("Il faut min < max.".to_string());
+    }
+    if (n_threads == 0) {
+        return Err("Le nombre de threads doit être au moins 1.".to_string());
+    }
 
+    let mut duree_recherche_premiers: Option<f64> = None;
+    let mut premiers: Vec<usize> = Vec::new();
+    if (n_threads == 1 || args.drapeau("compare")) {
         let chrono_start = get_curr_time_epoch();
         println!("Test recherche_premiers() min_n:{}, max_n:{}", min_n, max_n);
-        let premiers: Vec<usize> = divers::recherche_premiers(min_n, max_n);
-        //println!("Nombres premiers : {:?}", premiers);
+        premiers = divers::recherche_premiers(min_n, max_n);
         println!(
             "Nombre total de nombres premiers trouvés: {}",
             premiers.len()
         );
         let chrono_end = get_curr_time_epoch();
-        let duree_recherche_premiers = (chrono_end - chrono_start);
-        println!("Durée des calculs: {}", duree_recherche_premiers);
+        duree_recherche_premiers = Some(chrono_end - chrono_start);
+        println!("Durée des calculs: {}", chrono_end - chrono_start);
+    }
 
+    if (n_threads > 1) {
+        // recherche_premiers_multithreading lance un thread par paquet
+        // => autant de paquets que de threads demandés
+        let batch_size: usize = (max_n - min_n).div_ceil(n_threads);
         let chrono_start = get_curr_time_epoch();
-        println!("\n");
-        let batch_size: usize = (max_n - min_n) / 40;
         println!(
             "Test recherche_premiers_multithreading() min_n:{}, max_n:{}, batch_size:{}",
             min_n, max_n, batch_size
         );
-        let premiers: Vec<usize> =
-            divers::recherche_premiers_multithreading(min_n, max_n, batch_size);
+        premiers = divers::recherche_premiers_multithreading(min_n, max_n, batch_size);
+        // Les paquets sont traités dans un ordre quelconque
+        premiers.sort();
         println!(
             "Nombre total de nombres premiers trouvés: {}",
             premiers.len()
@@ -255,246 +879,580 @@ fn main() {
             "Durée des calculs: {}",
             duree_recherche_premiers_mutithreading
         );
-        println!(
-            "ratio de durée avec multithreading / sans multithreading : {}",
-            duree_recherche_premiers / duree_recherche_premiers_mutithreading
-        );
+        if let Some(duree) = duree_recherche_premiers {
+            println!(
+                "ratio de durée avec multithreading / sans multithreading : {}",
+                duree / duree_recherche_premiers_mutithreading
+            );
+        }
+    }
 
-        // Test de la conjecture de Syracuse
-        println!("\nTest de la conjecture de Syracuse");
-        let n: u64 = 15;
-        let (temps_de_vol, altitude_max) = divers::calcule_temps_de_vol_et_altitude_max(n);
-        println!(
-            "n = {}; Temps de vol = {}; Altitude max = {}",
-            n, temps_de_vol, altitude_max
+    if (args.drapeau("affiche")) {
+        println!("Nombres premiers : {:?}", premiers);
+    }
+    Ok(())
+}
+
+// Test de la conjecture de Syracuse
+fn commande_syracuse(args: &ArgumentsCommande) -> Result<(), String> {
+    let n_opt: Option<u64> = if (args.nb_positionnels() > 0) {
+        Some(args.positionnel(0, "n")?)
+    } else {
+        None
+    };
+    let n_max_opt: Option<u64> = args.option("n-max")?;
+
+    if (n_opt == Some(0) || n_max_opt == Some(0)) {
+        return Err(
+            "Les suites de Syracuse démarrent à un entier strictement positif.".to_string(),
         );
-        let n: u64 = 27;
+    }
+
+    // Sans argument: valeurs de démonstration
+    let (n_vec, n_max_vec): (Vec<u64>, Vec<u64>) = if (n_opt.is_none() && n_max_opt.is_none()) {
+        (vec![15, 27], vec![100, 1000000])
+    } else {
+        (n_opt.into_iter().collect(), n_max_opt.into_iter().collect())
+    };
+
+    println!("\nTest de la conjecture de Syracuse");
+    for n in n_vec {
         let (temps_de_vol, altitude_max) = divers::calcule_temps_de_vol_et_altitude_max(n);
         println!(
             "n = {}; Temps de vol = {}; Altitude max = {}",
             n, temps_de_vol, altitude_max
         );
-        let n_max: u64 = 100;
-        let (temps_de_vol_max, temps_de_vol_max_index) = divers::calcule_temps_de_vol_max(n_max);
-        println!(
-            "n_max = {}; Temps de vol max = {}; index associé = {}",
-            n_max, temps_de_vol_max, temps_de_vol_max_index
-        );
-        let n_max: u64 = 1000000;
+    }
+    for n_max in n_max_vec {
         let (temps_de_vol_max, temps_de_vol_max_index) = divers::calcule_temps_de_vol_max(n_max);
         println!(
             "n_max = {}; Temps de vol max = {}; index associé = {}",
             n_max, temps_de_vol_max, temps_de_vol_max_index
         );
-        //let (temps_de_vol_max, temps_de_vol_max_index) = divers::calcule_temps_de_vol_max_asm(n_max.clone());
-        //println!("n_max = {}; Temps de vol max (asm) = {}; index associé = {}", n_max, temps_de_vol_max, temps_de_vol_max_index);
-    }
-
-    if (b_test_rationnels) {
-        // Test addition
-        let r1 = rationnels::Rationnels::new(2i64, 3i64);
-        let r2 = rationnels::Rationnels::new(5i64, 6i64);
-        let r3 = &r1 + &r2;
-        println!("{} + {} = {} (somme avec références)", &r1, &r2, &r3);
-
-        let r3 = r1 + r2;
-        println!("meme somme = {} (somme sans référence)", &r3);
-
-        // Test AddAssign
-        let r1 = rationnels::Rationnels::new(2i64, 3i64);
-        let mut r2 = rationnels::Rationnels::new(5i64, 6i64);
-        r2 += r1;
-        println!("meme somme = {} (somme avec l'opérateur '+=' )\n", &r3);
-
-        // Test soustraction
-        let r1 = rationnels::Rationnels::new(2i64, 3i64);
-        let r2 = rationnels::Rationnels::new(5i64, 6i64);
-        let r3 = &r1 - &r2;
-        println!("{} - {} = {} (soustraction avec références)", &r1, &r2, &r3);
-
-        let r3 = r1 - r2;
-        println!("meme soustraction = {} (sans référence)", &r3);
-
-        // Test SubAssign
-        let r1 = rationnels::Rationnels::new(2i64, 3i64);
-        let mut r2 = rationnels::Rationnels::new(5i64, 6i64);
-        r2 -= r1;
-        println!(
-            "meme soustraction = {} (soustraction avec l'opérateur '-=' )\n",
-            &r3
-        );
+    }
+    Ok(())
+}
 
-        // Test multiplication
-        let r1 = rationnels::Rationnels::new(2i64, 3i64);
-        let r2 = rationnels::Rationnels::new(5i64, 6i64);
-        let r3 = &r1 * &r2;
-        println!(
-            "{} * {} = {} (multiplication avec références)",
-            &r1, &r2, &r3
-        );
+fn commande_rationnels() -> Result<(), String> {
+    // Test addition
+    let r1 = rationnels::Rationnels::new(2i64, 3i64);
+    let r2 = rationnels::Rationnels::new(5i64, 6i64);
+    let r3 = &r1 + &r2;
+    println!("{} + {} = {} (somme avec références)", &r1, &r2, &r3);
+
+    let r3 = r1 + r2;
+    println!("meme somme = {} (somme sans référence)", &r3);
+
+    // Test AddAssign
+    let r1 = rationnels::Rationnels::new(2i64, 3i64);
+    let mut r2 = rationnels::Rationnels::new(5i64, 6i64);
+    r2 += r1;
+    println!("meme somme = {} (somme avec l'opérateur '+=' )\n", &r3);
+
+    // Test soustraction
+    let r1 = rationnels::Rationnels::new(2i64, 3i64);
+    let r2 = rationnels::Rationnels::new(5i64, 6i64);
+    let r3 = &r1 - &r2;
+    println!("{} - {} = {} (soustraction avec références)", &r1, &r2, &r3);
+
+    let r3 = r1 - r2;
+    println!("meme soustraction = {} (sans référence)", &r3);
+
+    // Test SubAssign
+    let r1 = rationnels::Rationnels::new(2i64, 3i64);
+    let mut r2 = rationnels::Rationnels::new(5i64, 6i64);
+    r2 -= r1;
+    println!(
+        "meme soustraction = {} (soustraction avec l'opérateur '-=' )\n",
+        &r3
+    );
+
+    // Test multiplication
+    let r1 = rationnels::Rationnels::new(2i64, 3i64);
+    let r2 = rationnels::Rationnels::new(5i64, 6i64);
+    let r3 = &r1 * &r2;
+    println!(
+        "{} * {} = {} (multiplication avec références)",
+        &r1, &r2, &r3
+    );
+
+    let r3 = r1 * r2;
+    println!("meme multiplication = {} (sans référence)\n", &r3);
+
+    // Test division
+    let r1 = rationnels::Rationnels::new(2i64, 3i64);
+    let r2 = rationnels::Rationnels::new(5i64, 6i64);
+    let r3 = &r1 / &r2;
+    println!(
+        "{} / {} = {} (multiplication avec références)",
+        &r1, &r2, &r3
+    );
+
+    let r3 = r1 / r2;
+    println!("meme division = {} (sans référence)\n", &r3);
+
+    // Test négation
+    let r1 = rationnels::Rationnels::new(2i64, 3i64);
+    //let r2 = rationnels::Rationnels::new(5i64, 6i64);
+    let r1neg = -&r1;
+    println!("-({}) = {} (négation avec référence)", &r1, &r1neg);
+    //let r1 = rationnels::Rationnels::new(2i64, 3i64);
+    let r1neg = -r1;
+    println!("    = {} (sans référence)\n", &r1neg);
+
+    // Test comparaison
+    let r1 = rationnels::Rationnels::new(2i64, 3i64);
+    let r2 = rationnels::Rationnels::new(5i64, 6i64);
+    println!("{} >= {} ? Réponse: {}", &r1, &r2, r1 >= r2);
+    println!("{} > {} ? Réponse: {}", &r1, &r2, r1 > r2);
+    println!("{} <= {} ? Réponse: {}", &r1, &r2, r1 <= r2);
+    println!("{} < {} ? Réponse: {}", &r1, &r2, r1 < r2);
+    #[allow(clippy::eq_op)]
+    let b_cmp = r1 >= r1;
+    println!("{} >= {} ? Réponse: {}", &r1, &r1, b_cmp);
+    #[allow(clippy::eq_op)]
+    let b_cmp = r1 <= r1;
+    println!("{} <= {} ? Réponse: {}", &r1, &r1, b_cmp);
+
+    // Test comparison, via un des algos de tri générique
+    let mut mon_tableau_de_rationnels: Vec<Rationnels<i64>> = vec![
+        rationnels::Rationnels::new(2, 3),
+        rationnels::Rationnels::new(-2, 3),
+        rationnels::Rationnels::new(-1, 2),
+        rationnels::Rationnels::new(2, 5),
+        rationnels::Rationnels::new(-7, 5),
+    ];
+
+    let mon_tableau_de_rationnels2: &mut [Rationnels<i64>] =
+        mon_tableau_de_rationnels.as_mut_slice();
+    println!("\ntableau départ: \n {:?}", &mon_tableau_de_rationnels2);
+    tri_variantes::tri_par_insertion_generique(mon_tableau_de_rationnels2);
+    println!("\ntableau trié: \n {:?}\n", &mon_tableau_de_rationnels2);
+
+    // Test de conversion rationnel vers flottant
+    let r1 = rationnels::Rationnels::new(2i32, 3i32);
+    // On clone r1, car il est consommé par la conversion qui suit, mais on veut quand meme l'afficher..
+    let r1_clone = r1.clone();
+    let r1_flottant: f64 = f64::from(r1);
+    println!("{} = {}", &r1_clone, r1_flottant);
+
+    // Test de conversion entier vers rationnel
+    let entier_i64: i64 = 5;
+    let r1 = Rationnels::<i64>::from(entier_i64);
+    println!("{} = {}", &entier_i64, r1);
+
+    Ok(())
+}
 
-        let r3 = r1 * r2;
-        println!("meme multiplication = {} (sans référence)\n", &r3);
+fn commande_fichiers() -> Result<(), String> {
+    // Test des accés aux fichiers
+    let fichier_chemin = "./divers/mon_fichier.txt".to_string();
+
+    let contenu_a_ecrire: String = "Première ligne\nSeconde ligne".to_string();
+    println!("Ecriture dans le fichier {}", fichier_chemin);
+    fichiers::ecrire_fichier_texte(&fichier_chemin, &contenu_a_ecrire)?;
+    println!("Contenu écrit: {}", contenu_a_ecrire);
+    println!(" ");
+
+    let existe: bool = fichiers::test_existence_fichier(&fichier_chemin);
+    println!("Le fichier {} existe: {}", fichier_chemin, existe);
+    println!(" ");
+
+    let contenu_fichier: String = fichiers::lire_fichier_texte(&fichier_chemin)?;
+    println!("Contenu du fichier:");
+    println!("{:?}", &contenu_fichier);
+    println!(" ");
+
+    let lignes: Vec<String> = fichiers::lire_fichier_texte_lignes(&fichier_chemin, None)?;
+    println!("Liste de lignes lues avec le sépateur par défaut:");
+    println!("{:?}", &lignes);
+    println!(" ");
+
+    let contenu_binaire: Vec<u8> = vec![0x42, 0x6f, 0x6e, 0x6a, 0x6f, 0x75, 0x72];
+    //                            idem [66, 111, 110, 106, 111, 116, 114];
+    let fichier_binaire_chemin = "./divers/mon_fichier.dat".to_string();
+    println!("Ecriture dans le fichier {}", fichier_binaire_chemin);
+    fichiers::ecrire_fichier_binaire(&fichier_binaire_chemin, &contenu_binaire)?;
+    println!("Contenu écrit: {:?}", contenu_binaire);
+    println!(" ");
+
+    let contenu_binaire_lu: Vec<u8> = fichiers::lire_fichier_binaire(&fichier_binaire_chemin)?;
+    assert_eq!(
+        contenu_binaire, contenu_binaire_lu,
+        "Erreur: Le contenu diffère de celui attendu"
+    );
+    println!("Contenu du fichier:");
+    println!("{:?}", &contenu_binaire);
+    let contenu: String = String::from_utf8(contenu_binaire.clone()).unwrap();
+    println!("{}", &contenu);
+    println!(" ");
+
+    let dossier_chemin = "./".to_string();
+    let contenu_dossier: Vec<String> = fichiers::liste_dossier(&dossier_chemin)?;
+    println!("Contenu du dossier '{}' :", dossier_chemin);
+    println!("{:?}", &contenu_dossier);
+    println!(" ");
+
+    let fichier_chemin = "./divers/mon_fichier.txt".to_string();
+    let taille = fichiers::donne_taille_fichier(&fichier_chemin)?;
+    println!("Taille du fichier '{}' : {:?}", fichier_chemin, taille);
+    println!(" ");
+
+    let fichier_chemin = "./divers/mon_fichier.txt".to_string();
+    let type_fichier = fichiers::donne_infos_fichier(&fichier_chemin)?;
+    println!("Type du fichier '{}' : {:?}", fichier_chemin, type_fichier);
+    println!(" ");
+
+    let fichier_chemin = "./.git".to_string();
+    let type_fichier = fichiers::donne_infos_fichier(&fichier_chemin)?;
+    println!("Type du fichier '{}' : {:?}", fichier_chemin, type_fichier);
+    println!(" ");
+
+    Ok(())
+}
 
-        // Test division
-        let r1 = rationnels::Rationnels::new(2i64, 3i64);
-        let r2 = rationnels::Rationnels::new(5i64, 6i64);
-        let r3 = &r1 / &r2;
-        println!(
-            "{} / {} = {} (multiplication avec références)",
-            &r1, &r2, &r3
-        );
+// Chemin du fichier solution associé à un plan: 'plan.txt' -> 'plan_solution.txt'
+fn chemin_plan_solution(f_plan_labyrinthe: &str) -> String {
+    let base: &str = f_plan_labyrinthe
+        .strip_suffix(".txt")
+        .unwrap_or(f_plan_labyrinthe);
+    base.to_string() + "_solution.txt"
+}
 
-        let r3 = r1 / r2;
-        println!("meme division = {} (sans référence)\n", &r3);
-
-        // Test négation
-        let r1 = rationnels::Rationnels::new(2i64, 3i64);
-        //let r2 = rationnels::Rationnels::new(5i64, 6i64);
-        let r1neg = -&r1;
-        println!("-({}) = {} (négation avec référence)", &r1, &r1neg);
-        //let r1 = rationnels::Rationnels::new(2i64, 3i64);
-        let r1neg = -r1;
-        println!("    = {} (sans référence)\n", &r1neg);
-
-        // Test comparaison
-        let r1 = rationnels::Rationnels::new(2i64, 3i64);
-        let r2 = rationnels::Rationnels::new(5i64, 6i64);
-        println!("{} >= {} ? Réponse: {}", &r1, &r2, r1 >= r2);
-        println!("{} > {} ? Réponse: {}", &r1, &r2, r1 > r2);
-        println!("{} <= {} ? Réponse: {}", &r1, &r2, r1 <= r2);
-        println!("{} < {} ? Réponse: {}", &r1, &r2, r1 < r2);
-        #[allow(clippy::eq_op)]
-        let b_cmp = r1 >= r1;
-        println!("{} >= {} ? Réponse: {}", &r1, &r1, b_cmp);
-        #[allow(clippy::eq_op)]
-        let b_cmp = r1 <= r1;
-        println!("{} <= {} ? Réponse: {}", &r1, &r1, b_cmp);
-
-        // Test comparison, via un des algos de tri générique
-        let mut mon_tableau_de_rationnels: Vec<Rationnels<i64>> = vec![
-            rationnels::Rationnels::new(2, 3),
-            rationnels::Rationnels::new(-2, 3),
-            rationnels::Rationnels::new(-1, 2),
-            rationnels::Rationnels::new(2, 5),
-            rationnels::Rationnels::new(-7, 5),
-        ];
-
-        let mon_tableau_de_rationnels2: &mut [Rationnels<i64>] =
-            mon_tableau_de_rationnels.as_mut_slice();
-        println!("\ntableau départ: \n {:?}", &mon_tableau_de_rationnels2);
-        tri_variantes::tri_par_insertion_generique(mon_tableau_de_rationnels2);
-        println!("\ntableau trié: \n {:?}\n", &mon_tableau_de_rationnels2);
-
-        // Test de conversion rationnel vers flottant
-        let r1 = rationnels::Rationnels::new(2i32, 3i32);
-        // On clone r1, car il est consommé par la conversion qui suit, mais on veut quand meme l'afficher..
-        let r1_clone = r1.clone();
-        let r1_flottant: f64 = f64::from(r1);
-        println!("{} = {}", &r1_clone, r1_flottant);
-
-        // Test de conversion entier vers rationnel
-        let entier_i64: i64 = 5;
-        let r1 = Rationnels::<i64>::from(entier_i64);
-        println!("{} = {}", &entier_i64, r1);
-    }
-
-    if (b_test_fichiers) {
-        // Test des accés aux fichiers
-        let fichier_chemin = "./divers/mon_fichier.txt".to_string();
-
-        let contenu_a_ecrire: String = "Première ligne\nSeconde ligne".to_string();
-        println!("Ecriture dans le fichier {}", fichier_chemin);
-        fichiers::ecrire_fichier_texte(&fichier_chemin, &contenu_a_ecrire);
-        println!("Contenu écrit: {}", contenu_a_ecrire);
-        println!(" ");
-
-        let existe: bool = fichiers::test_existence_fichier(&fichier_chemin);
-        println!("Le fichier {} existe: {}", fichier_chemin, existe);
-        println!(" ");
-
-        let contenu_fichier: String = fichiers::lire_fichier_texte(&fichier_chemin);
-        println!("Contenu du fichier:");
-        println!("{:?}", &contenu_fichier);
-        println!(" ");
-
-        let lignes: Vec<String> = fichiers::lire_fichier_texte_lignes(&fichier_chemin, None);
-        println!("Liste de lignes lues avec le sépateur par défaut:");
-        println!("{:?}", &lignes);
-        println!(" ");
-
-        let contenu_binaire: Vec<u8> = vec![0x42, 0x6f, 0x6e, 0x6a, 0x6f, 0x75, 0x72];
-        //                            idem [66, 111, 110, 106, 111, 116, 114];
-        let fichier_binaire_chemin = "./divers/mon_fichier.dat".to_string();
-        println!("Ecriture dans le fichier {}", fichier_binaire_chemin);
-        fichiers::ecrire_fichier_binaire(&fichier_binaire_chemin, &contenu_binaire);
-        println!("Contenu écrit: {:?}", contenu_binaire);
-        println!(" ");
-
-        let contenu_binaire_lu: Vec<u8> = fichiers::lire_fichier_binaire(&fichier_binaire_chemin);
-        assert_eq!(
-            contenu_binaire, contenu_binaire_lu,
-            "Erreur: Le contenu diffère de celui attendu"
+// Algorithme de recherche choisi par les options --algo et --heuristique
+fn lit_algo_recherche(args: &ArgumentsCommande) -> Result<graphes::AlgoRecherche, String> {
+    let heuristique_str: String = args.option_ou("heuristique", "manhattan".to_string())?;
+    let heuristique = match (heuristique_str.as_str()) {
+        "manhattan" => graphes::Heuristique::Manhattan,
+        "euclidienne" => graphes::Heuristique::Euclidienne,
+        "octile" => graphes::Heuristique::Octile,
+        _ => return Err(format!("Heuristique inconnue: {}", heuristique_str)),
+    };
+    let algo_str: String = args.option_ou("algo", "dijkstra".to_string())?;
+    match (algo_str.as_str()) {
+        "largeur" => Ok(graphes::AlgoRecherche::Largeur),
+        "dijkstra" => Ok(graphes::AlgoRecherche::Dijkstra),
+        "a-etoile" => Ok(graphes::AlgoRecherche::AEtoile(heuristique)),
+        "glouton" => Ok(graphes::AlgoRecherche::Glouton(heuristique)),
+        _ => Err(format!("Algorithme inconnu: {}", algo_str)),
+    }
+}
+
+fn commande_labyrinthe(args: &ArgumentsCommande) -> Result<(), String> {
+    let algo = lit_algo_recherche(args)?;
+    let images: bool = args.drapeau("images");
+    let diagonales: bool = args.drapeau("diagonales");
+    let legende = if (args.drapeau("terrain")) {
+        graphes::Legende::terrain()
+    } else {
+        graphes::Legende::standard()
+    };
+    if (args.nb_positionnels() > 0) {
+        if (args.option::<String>("dossier")?.is_some()) {
+            return Err("Indiquer soit un plan, soit un dossier, mais pas les deux.".to_string());
+        }
+        let f_plan_labyrinthe: String = args.positionnel(0, "plan.txt")?;
+        let f_plan_solution: String =
+            args.positionnel_ou(1, "solution.txt", chemin_plan_solution(&f_plan_labyrinthe))?;
+        graphes::resoud_labyrinthe(
+            f_plan_labyrinthe,
+            f_plan_solution,
+            algo,
+            &legende,
+            diagonales,
+            images,
+        )?;
+        return Ok(());
+    }
+
+    let d_labyrinthes: String = args.option_ou("dossier", "divers/labyrinthes".to_string())?;
+    let mut f_liste_plan_labyrinthes: Vec<String> = fichiers::liste_dossier(&d_labyrinthes)?;
+    f_liste_plan_labyrinthes.sort();
+
+    for f_plan_labyrinthe in f_liste_plan_labyrinthes {
+        if (f_plan_labyrinthe.contains("solution") || !f_plan_labyrinthe.ends_with(".txt")) {
+            continue;
+        }
+
+        let f_plan_solution = chemin_plan_solution(&f_plan_labyrinthe);
+        //println!("{},{}", f_plan_labyrinthe, f_plan_solution);
+        graphes::resoud_labyrinthe(
+            f_plan_labyrinthe,
+            f_plan_solution,
+            algo,
+            &legende,
+            diagonales,
+            images,
+        )?;
+    }
+    Ok(())
+}
+
+fn commande_genere_labyrinthe(args: &ArgumentsCommande) -> Result<(), String> {
+    let hauteur: usize = args.option_ou("hauteur", 10)?;
+    let largeur: usize = args.option_ou("largeur", 20)?;
+    let seed: u32 = lit_seed(args)?;
+    if (hauteur == 0 || largeur == 0) {
+        return Err("Le labyrinthe doit avoir au moins une case.".to_string());
+    }
+    let algo_str: String = args.option_ou("algo", "retour-sur-trace".to_string())?;
+    let algo = match (algo_str.as_str()) {
+        "retour-sur-trace" => generateur_labyrinthes::AlgoGeneration::RetourSurTrace,
+        "kruskal" => generateur_labyrinthes::AlgoGeneration::Kruskal,
+        "prim" => generateur_labyrinthes::AlgoGeneration::Prim,
+        "wilson" => generateur_labyrinthes::AlgoGeneration::Wilson,
+        _ => return Err(format!("Algorithme inconnu: {}", algo_str)),
+    };
+
+    let plan: Vec<String> = generateur_labyrinthes::genere_labyrinthe(hauteur, largeur, algo, seed);
+    if (args.nb_positionnels() > 0) {
+        let f_plan: String = args.positionnel(0, "sortie.txt")?;
+        fichiers::ecrire_fichier_texte_lignes(&f_plan, &plan)?;
+    } else {
+        println!("{}", plan.join("\n"));
+    }
+    Ok(())
+}
+
+// Durée de l'algorithme de Dijkstra sur des labyrinthes générés de taille croissante
+// Avec la file de priorité en tas binaire, durée / (V.log(V)) doit rester à peu près constant
+fn commande_dijkstra_bench(args: &ArgumentsCommande) -> Result<(), String> {
+    let tailles_str: String = args.option_ou("tailles", "100,200,400".to_string())?;
+    let proportion_murs: f64 = args.option_ou("proportion-murs", 0.2)?;
+    let seed: u32 = lit_seed(args)?;
+    if (!(0. ..1.).contains(&proportion_murs)) {
+        return Err("La proportion de murs doit être comprise dans [0, 1[.".to_string());
+    }
+
+    let mut tailles: Vec<usize> = Vec::new();
+    for taille_str in tailles_str.split(',') {
+        let taille: usize = taille_str
+            .trim()
+            .parse()
+            .map_err(|_| format!("Taille invalide: '{}'", taille_str))?;
+        if (taille < 3) {
+            return Err("Les tailles doivent être supérieures ou égales à 3.".to_string());
+        }
+        tailles.push(taille);
+    }
+
+    println!("taille | sommets parcourus | distance | durée (s) | durée / (V.log(V)) (ns)");
+    for taille in tailles {
+        let plan: Vec<String> =
+            graphes::genere_plan_aleatoire(taille, taille, proportion_murs, seed);
+        let labyrinthe: graphes::Labyrinthe = graphes::Labyrinthe::new(&plan);
+
+        let chrono_start = get_curr_time_epoch();
+        let (p, _prec, sommet_final_opt): (
+            HashMap<Position, u64>,
+            HashMap<Position, Position>,
+            Option<Position>,
+        ) = graphes::resoud_dijstra(&labyrinthe, labyrinthe.s_init(), labyrinthe.s_final())
+            .map_err(|e| e.to_string())?;
+        let duree = get_curr_time_epoch() - chrono_start;
+
+        let n_sommets: f64 = p.len() as f64;
+        let distance: String = match (sommet_final_opt) {
+            Some(sommet_final) => p[&sommet_final].to_string(),
+            None => "aucun chemin".to_string(),
+        };
+        println!(
+            "{} | {} | {} | {} | {:.1}",
+            taille,
+            p.len(),
+            distance,
+            duree,
+            1e9 * duree / (n_sommets * n_sommets.max(2.).ln())
         );
-        println!("Contenu du fichier:");
-        println!("{:?}", &contenu_binaire);
-        let contenu: String = String::from_utf8(contenu_binaire.clone()).unwrap();
-        println!("{}", &contenu);
-        println!(" ");
+    }
+    Ok(())
+}
 
-        let dossier_chemin = "./".to_string();
-        let contenu_dossier: Vec<String> = fichiers::liste_dossier(&dossier_chemin);
-        println!("Contenu du dossier '{}' :", dossier_chemin);
-        println!("{:?}", &contenu_dossier);
-        println!(" ");
+// Affiche le plus court chemin de 'source' à 'destination' dans le graphe
+fn affiche_plus_court_chemin<S>(
+    graphe: &GraphePondere<S, i64>,
+    source: S,
+    destination: S,
+    bellman_ford: bool,
+    f_dot: Option<String>,
+    f_svg: Option<String>,
+) -> Result<(), String>
+where
+    S: Eq + std::hash::Hash + Clone + std::fmt::Display + std::fmt::Debug,
+{
+    println!(
+        "Graphe: {} sommets, {} arêtes",
+        graphe.nb_sommets(),
+        graphe.nb_aretes()
+    );
+    for sommet in [&source, &destination] {
+        if (!graphe.contient_sommet(sommet)) {
+            return Err(format!("Sommet absent du graphe: {}", sommet));
+        }
+    }
 
-        let fichier_chemin = "./divers/mon_fichier.txt".to_string();
-        let taille = fichiers::donne_taille_fichier(&fichier_chemin);
-        println!("Taille du fichier '{}' : {:?}", fichier_chemin, taille);
-        println!(" ");
+    let chrono_start = get_curr_time_epoch();
+    let (p, prec) = if (bellman_ford) {
+        graphes::resoud_bellman_ford(graphe, vec![source.clone()]).map_err(|e| e.to_string())?
+    } else {
+        let (p, prec, _sommet_final_opt) =
+            graphes::resoud_dijstra(graphe, vec![source.clone()], vec![destination.clone()])
+                .map_err(|e| e.to_string())?;
+        (p, prec)
+    };
+    let duree = get_curr_time_epoch() - chrono_start;
+
+    let chemin_opt = graphes::reconstruit_chemin(&p, &prec, &destination);
+    match (&chemin_opt) {
+        Some(chemin) => {
+            let sommets_str: Vec<String> = chemin.sommets.iter().map(|s| s.to_string()).collect();
+            println!("Plus court chemin: {}", sommets_str.join(" -> "));
+            println!("Distance: {}", chemin.cout);
+        }
+        None => println!("Pas de chemin de {} à {}", source, destination),
+    }
+    println!("Sommets parcourus: {}, durée: {:.6} s", p.len(), duree);
 
-        let fichier_chemin = "./divers/mon_fichier.txt".to_string();
-        let type_fichier = fichiers::donne_infos_fichier(&fichier_chemin);
-        println!("Type du fichier '{}' : {:?}", fichier_chemin, type_fichier);
-        println!(" ");
+    let mise_en_evidence =
+        dessin_graphes::MiseEnEvidence::depuis_recherche(&p, chemin_opt.as_ref());
+    if let Some(f_dot) = f_dot {
+        dessin_graphes::sauve_dot(graphe, graphe.est_oriente(), &mise_en_evidence, &f_dot)?;
+    }
+    if let Some(f_svg) = f_svg {
+        let disposition = dessin_graphes::Disposition::Forces {
+            iterations: 300,
+            seed: 1234,
+        };
+        dessin_graphes::dessine_graphe_svg(
+            graphe,
+            graphe.est_oriente(),
+            disposition,
+            &mise_en_evidence,
+            &f_svg,
+        )?;
+    }
+    Ok(())
+}
 
-        let fichier_chemin = "./.git".to_string();
-        let type_fichier = fichiers::donne_infos_fichier(&fichier_chemin);
-        println!("Type du fichier '{}' : {:?}", fichier_chemin, type_fichier);
-        println!(" ");
+fn commande_graphe(args: &ArgumentsCommande) -> Result<(), String> {
+    let f_graphe: String = args.positionnel(0, "graphe.txt")?;
+    let bellman_ford: bool = args.drapeau("bellman-ford");
+    let f_dot: Option<String> = args.option("dot")?;
+    let f_svg: Option<String> = args.option("svg")?;
+    if (args.drapeau("dimacs")) {
+        // Les arcs DIMACS sont orientés (un réseau routier liste les 2 sens)
+        let graphe: GraphePondere<usize, i64> = GraphePondere::charge_dimacs(&f_graphe, true)?;
+        let source: usize = args.positionnel(1, "source")?;
+        let destination: usize = args.positionnel(2, "destination")?;
+        return affiche_plus_court_chemin(&graphe, source, destination, bellman_ford, f_dot, f_svg);
     }
 
-    if (b_test_conversions_entiers) {
-        conversions_hexa_bin_dec::conversions_entier();
+    let graphe: GraphePondere<String, i64> =
+        GraphePondere::charge_liste_aretes(&f_graphe, args.drapeau("oriente"))?;
+    let source: String = args.positionnel(1, "source")?;
+    let destination: String = args.positionnel(2, "destination")?;
+    affiche_plus_court_chemin(&graphe, source, destination, bellman_ford, f_dot, f_svg)
+}
+
+fn commande_koch(args: &ArgumentsCommande) -> Result<(), String> {
+    let hauteur: u32 = args.option_ou("hauteur", 1024)?;
+    let largeur: u32 = args.option_ou("largeur", 1920)?;
+    let n_iter: u32 = args.option_ou("n-iter", 4)?;
+    let f_flocon_svg: String =
+        args.positionnel_ou(0, "sortie.svg", "images/flocon_Koch.svg".to_string())?;
+    if (hauteur < 10 || largeur < 10) {
+        return Err("L'image doit mesurer au moins 10x10 pixels.".to_string());
     }
+    if (n_iter > 8) {
+        return Err("Le nombre d'itérations doit être inférieur ou égal à 8.".to_string());
+    }
+    let lignes: Vec<fractales::Ligne> = fractales::flocon_koch(hauteur, largeur, n_iter);
+    fractales::cree_fichier_svg_depuis_lignes(&f_flocon_svg, hauteur, largeur, lignes)?;
+    Ok(())
+}
 
-    if (b_test_graphes) {
-        let d_labyrinthes: String = "divers/labyrinthes".to_string();
-        let f_liste_plan_labyrinthes: Vec<String> = fichiers::liste_dossier(&d_labyrinthes);
+fn commande_fractale(args: &ArgumentsCommande) -> Result<(), String> {
+    let x_fractale: f64 = args.option_ou("cx", 0.3)?;
+    let y_fractale: f64 = args.option_ou("cy", 0.5)?;
+    let f_fractale_bmp: String =
+        args.positionnel_ou(0, "sortie.bmp", "images/fractale.bmp".to_string())?;
+    if (!x_fractale.is_finite() || !y_fractale.is_finite()) {
+        return Err("Les paramètres --cx et --cy doivent être des nombres finis.".to_string());
+    }
+    fractales::calcule_fractale_et_ecrit_bmp(x_fractale, y_fractale, &f_fractale_bmp)?;
+    Ok(())
+}
 
-        for f_plan_labyrinthe in f_liste_plan_labyrinthes {
-            if (f_plan_labyrinthe.contains("solution")) {
-                continue;
-            }
+fn execute_commande(nom: &str, args: &ArgumentsCommande) -> Result<(), String> {
+    match (nom) {
+        "classiques" => commande_classiques(),
+        "hanoi" => commande_hanoi(args),
+        "recherche" => commande_recherche(args),
+        "tri" => commande_tri(args),
+        "tri-variantes" => commande_tri_variantes(),
+        "tri-bench" => commande_tri_bench(args),
+        "tri-parallele" => commande_tri_parallele(args),
+        "probas" => commande_probas(args),
+        "dames" => commande_dames(),
+        "premiers" => commande_premiers(args),
+        "syracuse" => commande_syracuse(args),
+        "rationnels" => commande_rationnels(),
+        "fichiers" => commande_fichiers(),
+        "conversions" => {
+            conversions_hexa_bin_dec::conversions_entier();
+            Ok(())
+        }
+        "labyrinthe" => commande_labyrinthe(args),
+        "genere-labyrinthe" => commande_genere_labyrinthe(args),
+        "dijkstra-bench" => commande_dijkstra_bench(args),
+        "graphe" => commande_graphe(args),
+        "koch" => commande_koch(args),
+        "fractale" => commande_fractale(args),
+        _ => Err(format!("Commande non aiguillée: {}", nom)),
+    }
+}
 
-            let f_plan_solution =
-                f_plan_labyrinthe.split('.').collect::<Vec<_>>()[0].to_string() + "_solution.txt";
-            //println!("{},{}", f_plan_labyrinthe, f_plan_solution);
-            graphes::resoud_labyrinthe(f_plan_labyrinthe, f_plan_solution);
+fn main() -> ExitCode {
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+
+    // Aide générale: 'algos', 'algos --help', 'algos help' (ou aide d'une commande: 'algos help tri')
+    let premier_argument: &str = arguments.first().map(|a| a.as_str()).unwrap_or("--help");
+    if (premier_argument == "--help" || premier_argument == "-h" || premier_argument == "help") {
+        match (arguments
+            .get(1)
+            .and_then(|nom| COMMANDES.iter().find(|c| c.nom == nom)))
+        {
+            Some(spec) => cli::affiche_aide_commande(NOM_BINAIRE, spec),
+            None => cli::affiche_aide_generale(NOM_BINAIRE, COMMANDES),
         }
+        return ExitCode::SUCCESS;
     }
 
-    if (b_test_fractales) {
-        let hauteur: u32 = 1024;
-        let largeur: u32 = 1920;
-        let f_flocon_svg: String = "images/flocon_Koch.svg".to_string();
-        let n_iter: u32 = 4;
-        let lignes: Vec<fractales::Ligne> = fractales::flocon_koch(hauteur, largeur, n_iter);
-        fractales::cree_fichier_svg_depuis_lignes(&f_flocon_svg, hauteur, largeur, lignes);
+    let spec: &SpecCommande = match (COMMANDES.iter().find(|c| c.nom == premier_argument)) {
+        Some(spec) => spec,
+        None => {
+            eprintln!("Erreur: Commande inconnue: {}\n", premier_argument);
+            cli::affiche_aide_generale(NOM_BINAIRE, COMMANDES);
+            return ExitCode::from(2);
+        }
+    };
 
-        let x_fractale = 0.3;
-        let y_fractale = 0.5;
-        let f_fractale_bmp: String = "images/fractale.bmp".to_string();
-        fractales::calcule_fractale_et_ecrit_bmp(x_fractale, y_fractale, &f_fractale_bmp);
+    match (cli::analyse_arguments(spec, &arguments[1..])) {
+        Ok(Analyse::Aide) => {
+            cli::affiche_aide_commande(NOM_BINAIRE, spec);
+            ExitCode::SUCCESS
+        }
+        Ok(Analyse::Arguments(args)) => match (execute_commande(spec.nom, &args)) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(message) => {
+                eprintln!("Erreur: {}", message);
+                ExitCode::FAILURE
+            }
+        },
+        Err(message) => {
+            eprintln!("Erreur: {}\n", message);
+            cli::affiche_aide_commande(NOM_BINAIRE, spec);
+            ExitCode::from(2)
+        }
     }
 }
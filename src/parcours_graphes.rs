@@ -0,0 +1,273 @@
+// Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
+#![allow(unused_parens)]
+
+// Parcours en largeur (BFS) et en profondeur (DFS) des graphes implémentant Voisins
+// Les distances (poids des arêtes) sont ignorées: seule la structure du graphe compte.
+// Pour un graphe dont toutes les arêtes ont le même poids (ex: Labyrinthe), le parcours en largeur
+// donne directement les plus courts chemins, sans la file de priorité de Dijkstra.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::Add;
+
+use crate::graphes::{Sommets, Voisins};
+
+// Sommet rencontré lors d'un parcours
+#[derive(Clone, Debug, PartialEq)]
+pub struct SommetParcouru<S> {
+    pub sommet: S,
+    // Nombre d'arêtes depuis le sommet de départ, dans l'arbre du parcours
+    pub profondeur: usize,
+    // Sommet depuis lequel il a été atteint (None pour les sommets de départ)
+    pub parent: Option<S>,
+}
+
+// Parcours en largeur: les sommets sont visités par profondeur croissante
+// https://fr.wikipedia.org/wiki/Algorithme_de_parcours_en_largeur
+pub struct ParcoursLargeur<'a, G, S, A> {
+    graphe: &'a G,
+    // File des sommets à visiter (chaque sommet n'y entre qu'une fois)
+    file: VecDeque<SommetParcouru<S>>,
+    // Sommets déjà mis dans la file
+    decouverts: HashSet<S>,
+    _distances: PhantomData<A>,
+}
+
+// Parcours en profondeur itératif (pile explicite, pas de récursion: pas de risque de débordement de pile)
+// https://fr.wikipedia.org/wiki/Algorithme_de_parcours_en_profondeur
+pub struct ParcoursProfondeur<'a, G, S, A> {
+    graphe: &'a G,
+    // Pile des sommets à visiter (un sommet peut y être plusieurs fois, il n'est visité que la première)
+    pile: Vec<SommetParcouru<S>>,
+    visites: HashSet<S>,
+    _distances: PhantomData<A>,
+}
+
+pub fn parcours_largeur<G, S, A>(graphe: &G, s_init: Vec<S>) -> ParcoursLargeur<'_, G, S, A>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    let mut file: VecDeque<SommetParcouru<S>> = VecDeque::new();
+    let mut decouverts: HashSet<S> = HashSet::new();
+    for sommet in s_init {
+        if (decouverts.insert(sommet.clone())) {
+            file.push_back(SommetParcouru {
+                sommet,
+                profondeur: 0,
+                parent: None,
+            });
+        }
+    }
+    ParcoursLargeur {
+        graphe,
+        file,
+        decouverts,
+        _distances: PhantomData,
+    }
+}
+
+pub fn parcours_profondeur<G, S, A>(graphe: &G, s_init: Vec<S>) -> ParcoursProfondeur<'_, G, S, A>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    // Sommets de départ empilés à l'envers, pour être visités dans l'ordre donné
+    let pile: Vec<SommetParcouru<S>> = s_init
+        .into_iter()
+        .rev()
+        .map(|sommet| SommetParcouru {
+            sommet,
+            profondeur: 0,
+            parent: None,
+        })
+        .collect();
+    ParcoursProfondeur {
+        graphe,
+        pile,
+        visites: HashSet::new(),
+        _distances: PhantomData,
+    }
+}
+
+impl<G, S, A> Iterator for ParcoursLargeur<'_, G, S, A>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    type Item = SommetParcouru<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sommet_cour = self.file.pop_front()?;
+        for (voisin, _dist) in self.graphe.liste_voisins_et_distances(&sommet_cour.sommet) {
+            if (self.decouverts.insert(voisin.clone())) {
+                self.file.push_back(SommetParcouru {
+                    sommet: voisin,
+                    profondeur: sommet_cour.profondeur + 1,
+                    parent: Some(sommet_cour.sommet.clone()),
+                });
+            }
+        }
+        Some(sommet_cour)
+    }
+}
+
+impl<G, S, A> Iterator for ParcoursProfondeur<'_, G, S, A>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    type Item = SommetParcouru<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(sommet_cour) = self.pile.pop() {
+            if (!self.visites.insert(sommet_cour.sommet.clone())) {
+                continue;
+            }
+            // Voisins empilés à l'envers: le premier voisin est exploré en premier (comme en récursif)
+            let voisins: Vec<(S, A)> = self.graphe.liste_voisins_et_distances(&sommet_cour.sommet);
+            for (voisin, _dist) in voisins.into_iter().rev() {
+                if (!self.visites.contains(&voisin)) {
+                    self.pile.push(SommetParcouru {
+                        sommet: voisin,
+                        profondeur: sommet_cour.profondeur + 1,
+                        parent: Some(sommet_cour.sommet.clone()),
+                    });
+                }
+            }
+            return Some(sommet_cour);
+        }
+        None
+    }
+}
+
+// Ensemble des sommets accessibles depuis les sommets de départ (sommets de départ inclus)
+pub fn sommets_accessibles<G, S, A>(graphe: &G, s_init: Vec<S>) -> HashSet<S>
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    parcours_largeur(graphe, s_init)
+        .map(|sommet_parcouru| sommet_parcouru.sommet)
+        .collect()
+}
+
+// Indique si 'destination' est accessible depuis 'source' (le parcours s'arrête dès qu'elle est atteinte)
+pub fn est_accessible<G, S, A>(graphe: &G, source: S, destination: &S) -> bool
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    parcours_largeur(graphe, vec![source])
+        .any(|sommet_parcouru| &sommet_parcouru.sommet == destination)
+}
+
+// Plus court chemin en nombre d'arêtes, par un parcours en largeur (arrêté au premier sommet final atteint)
+// Mêmes sorties que resoud_dijstra, les distances étant les profondeurs
+// (donc les vraies distances si toutes les arêtes ont un poids de 1)
+pub fn resoud_largeur<G, S, A>(
+    graphe: &G,
+    s_init: Vec<S>,
+    s_final: Vec<S>,
+) -> (HashMap<S, usize>, HashMap<S, S>, Option<S>)
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    let mut p: HashMap<S, usize> = HashMap::new();
+    let mut prec: HashMap<S, S> = HashMap::new();
+    for sommet_parcouru in parcours_largeur(graphe, s_init) {
+        p.insert(sommet_parcouru.sommet.clone(), sommet_parcouru.profondeur);
+        if let Some(parent) = sommet_parcouru.parent {
+            prec.insert(sommet_parcouru.sommet.clone(), parent);
+        }
+        if (s_final.contains(&sommet_parcouru.sommet)) {
+            return (p, prec, Some(sommet_parcouru.sommet));
+        }
+    }
+    (p, prec, None)
+}
+
+// Parcours en largeur comme resoud_largeur, mais les distances sont les coûts réels (somme des poids
+// des arêtes) le long de l'arbre du parcours, et non le nombre d'arêtes
+// Le chemin trouvé reste celui qui a le moins d'arêtes: il n'est pas forcément le moins coûteux
+// si les poids sont différents (terrains, diagonales)
+pub fn resoud_largeur_avec_couts<G, S, A>(
+    graphe: &G,
+    s_init: Vec<S>,
+    s_final: Vec<S>,
+) -> (HashMap<S, A>, HashMap<S, S>, Option<S>)
+where
+    G: Voisins<S, A>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add<Output = A> + TryFrom<i8> + Clone,
+    <A as TryFrom<i8>>::Error: Debug,
+{
+    let dist_zero: A = A::try_from(0i8).expect("Distance nulle manquante pour le type A.");
+    let mut p: HashMap<S, A> = HashMap::new();
+    let mut prec: HashMap<S, S> = HashMap::new();
+    // Les sommets sortent dans l'ordre du parcours: le coût du parent est toujours déjà connu
+    for sommet_parcouru in parcours_largeur(graphe, s_init) {
+        let cout: A = match (sommet_parcouru.parent) {
+            Some(parent) => {
+                let poids: A = graphe
+                    .liste_voisins_et_distances(&parent)
+                    .into_iter()
+                    .filter(|(voisin, _poids)| voisin == &sommet_parcouru.sommet)
+                    .map(|(_voisin, poids)| poids)
+                    .reduce(|poids_min, poids| {
+                        if (poids < poids_min) {
+                            poids
+                        } else {
+                            poids_min
+                        }
+                    })
+                    .expect("Arête du parcours introuvable.");
+                let cout_parent: A = p[&parent].clone();
+                prec.insert(sommet_parcouru.sommet.clone(), parent);
+                cout_parent + poids
+            }
+            None => dist_zero.clone(),
+        };
+        p.insert(sommet_parcouru.sommet.clone(), cout);
+        if (s_final.contains(&sommet_parcouru.sommet)) {
+            return (p, prec, Some(sommet_parcouru.sommet));
+        }
+    }
+    (p, prec, None)
+}
+
+// Composantes connexes: associe à chaque sommet le numéro de sa composante (0, 1, 2..)
+// numérotées dans l'ordre de liste_sommets()
+// Défini pour un graphe non orienté (ex: Labyrinthe, GraphePondere non orienté): pour un graphe orienté,
+// chaque sommet reçoit simplement la première composante qui l'atteint.
+pub fn composantes_connexes<G, S, A>(graphe: &G) -> HashMap<S, usize>
+where
+    G: Voisins<S, A> + Sommets<S>,
+    S: Eq + Hash + Clone,
+    A: PartialOrd + Add,
+{
+    let mut composantes: HashMap<S, usize> = HashMap::new();
+    let mut n_composantes: usize = 0;
+    for sommet in graphe.liste_sommets() {
+        if (composantes.contains_key(&sommet)) {
+            continue;
+        }
+        for sommet_parcouru in parcours_largeur(graphe, vec![sommet]) {
+            composantes
+                .entry(sommet_parcouru.sommet)
+                .or_insert(n_composantes);
+        }
+        n_composantes += 1;
+    }
+    composantes
+}
@@ -183,7 +183,7 @@ where
     if (n == 0) {
         return None;
     }
-    let delta_n: usize = if let Some(elem) = correction { elem } else { 1 };
+    let delta_n: usize = correction.unwrap_or(1);
 
     let mut somme_carres: T = T::from(mon_tableau[0].clone() * mon_tableau[0].clone());
 
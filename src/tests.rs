@@ -1,6 +1,19 @@
+use crate::arbres_couvrants::{self, EnsemblesDisjoints};
 use crate::classiques;
+use crate::cli::{self, Analyse, SpecCommande, SpecOption};
 use crate::divers;
+use crate::erreurs::Erreur;
+use crate::fichiers;
+use crate::generateur_labyrinthes::{self, AlgoGeneration};
+use crate::graphe_pondere::GraphePondere;
+use crate::graphes;
+use crate::graphes::{ErreurGraphe, Sommets, Voisins};
+use crate::parcours_graphes;
+use crate::probabilites::{self, RngMinstd};
 use crate::rationnels;
+use crate::tri;
+use crate::tri_sans_comparaison;
+use crate::tri_variantes;
 
 #[test]
 fn test_classiques_factorielle() {
@@ -124,3 +137,1424 @@ fn test_rationnels() {
         "Echec test_rationnels (4) : Division de références."
     );
 }
+
+#[test]
+fn test_fichiers_erreurs() {
+    let fichier_absent = "./divers/fichier_absent.txt".to_string();
+    assert!(matches!(
+        fichiers::lire_fichier_texte(&fichier_absent),
+        Err(Erreur::FichierIntrouvable(_))
+    ));
+    assert!(matches!(
+        fichiers::lire_fichier_binaire(&fichier_absent),
+        Err(Erreur::FichierIntrouvable(_))
+    ));
+    assert!(matches!(
+        fichiers::liste_dossier(&"./dossier_absent".to_string()),
+        Err(Erreur::FichierIntrouvable(_))
+    ));
+    assert!(matches!(
+        fichiers::donne_infos_fichier(&fichier_absent),
+        Err(Erreur::FichierIntrouvable(_))
+    ));
+
+    // Fichier binaire qui n'est pas de l'UTF-8 valide
+    let fichier_binaire = std::env::temp_dir()
+        .join("algorithmes_en_rust_test_utf8.dat")
+        .to_string_lossy()
+        .into_owned();
+    fichiers::ecrire_fichier_binaire(&fichier_binaire, &vec![0xff, 0xfe, 0x00]).unwrap();
+    assert!(matches!(
+        fichiers::lire_fichier_texte(&fichier_binaire),
+        Err(Erreur::Utf8Invalide(_))
+    ));
+    assert_eq!(
+        fichiers::lire_fichier_binaire(&fichier_binaire).unwrap(),
+        vec![0xff, 0xfe, 0x00]
+    );
+    let _ = std::fs::remove_file(&fichier_binaire);
+
+    // Les erreurs sont propagées par resoud_labyrinthe
+    assert!(matches!(
+        graphes::resoud_labyrinthe(
+            fichier_absent.clone(),
+            fichier_absent,
+            graphes::AlgoRecherche::Dijkstra,
+            &graphes::Legende::standard(),
+            false,
+            false
+        ),
+        Err(Erreur::FichierIntrouvable(_))
+    ));
+
+    // Plan terminé par un retour à la ligne: résolu; plan vide ou de largeur variable: erreur de format
+    let dossier = std::env::temp_dir();
+    let f_plan = dossier
+        .join("algorithmes_en_rust_test_plan.txt")
+        .to_string_lossy()
+        .into_owned();
+    let f_solution = dossier
+        .join("algorithmes_en_rust_test_plan_solution.txt")
+        .to_string_lossy()
+        .into_owned();
+    let resoud = |contenu: &str| {
+        fichiers::ecrire_fichier_texte(&f_plan, &contenu.to_string()).unwrap();
+        graphes::resoud_labyrinthe(
+            f_plan.clone(),
+            f_solution.clone(),
+            graphes::AlgoRecherche::Dijkstra,
+            &graphes::Legende::standard(),
+            false,
+            false,
+        )
+    };
+    assert!(resoud("#####\n#@ $#\n#####\n").is_ok());
+    assert_eq!(
+        fichiers::lire_fichier_texte(&f_solution).unwrap(),
+        "#####\n#@x$#\n#####"
+    );
+    assert!(matches!(resoud(""), Err(Erreur::FormatInvalide(_, 1, _))));
+    assert!(matches!(
+        resoud("#####\n#@ $#\n####\n"),
+        Err(Erreur::FormatInvalide(_, 3, _))
+    ));
+    let _ = std::fs::remove_file(&f_plan);
+    let _ = std::fs::remove_file(&f_solution);
+}
+
+// Petit graphe orienté pondéré, pour tester les algorithmes de graphes.rs
+// Sommets 0..=4; le chemin le plus court de 0 à 4 (0 -> 2 -> 1 -> 3 -> 4) n'est pas le plus direct
+struct GrapheTest {}
+
+impl Voisins<u32, u32> for GrapheTest {
+    fn liste_voisins_et_distances(&self, sommet: &u32) -> Vec<(u32, u32)> {
+        match (sommet) {
+            0 => vec![(1, 10), (2, 3)],
+            1 => vec![(3, 2)],
+            2 => vec![(1, 4), (3, 8), (4, 15)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+}
+
+impl Sommets<u32> for GrapheTest {
+    fn liste_sommets(&self) -> Vec<u32> {
+        (0..=4).collect()
+    }
+}
+
+// Graphe dont liste_sommets ne contient pas tous les voisins (9 et 10 n'y sont pas)
+// Les arcs vers ces sommets sont ignorés: le chemin 0 -> 9 -> 2 n'est pas pris en compte
+struct GrapheIncomplet {}
+
+impl Voisins<u32, i32> for GrapheIncomplet {
+    fn liste_voisins_et_distances(&self, sommet: &u32) -> Vec<(u32, i32)> {
+        match (sommet) {
+            0 => vec![(1, 4), (9, 1)],
+            1 => vec![(2, 1)],
+            9 => vec![(2, 1), (10, 1)],
+            _ => vec![],
+        }
+    }
+}
+
+impl Sommets<u32> for GrapheIncomplet {
+    fn liste_sommets(&self) -> Vec<u32> {
+        vec![0, 1, 2]
+    }
+}
+
+#[test]
+fn test_graphes_dijkstra() {
+    let graphe = GrapheTest {};
+    let (p, prec, sommet_final_opt) = graphes::resoud_dijstra(&graphe, vec![0], vec![4]).unwrap();
+    assert_eq!(sommet_final_opt, Some(4));
+    assert_eq!(p[&4], 10);
+    assert_eq!(p[&1], 7);
+    assert_eq!(prec[&4], 3);
+    assert_eq!(prec[&3], 1);
+    assert_eq!(prec[&1], 2);
+    let chemin = graphes::reconstruit_chemin(&p, &prec, &4).unwrap();
+    assert_eq!(chemin.sommets, vec![0, 2, 1, 3, 4]);
+    assert_eq!(chemin.cout, 10);
+    assert_eq!(graphes::reconstruit_chemin(&p, &prec, &5), None);
+
+    // Sommet final inaccessible: tout le graphe est parcouru
+    let (p, _prec, sommet_final_opt) = graphes::resoud_dijstra(&graphe, vec![3], vec![0]).unwrap();
+    assert_eq!(sommet_final_opt, None);
+    assert_eq!(p.len(), 2);
+
+    let plan: Vec<String> = fichiers::lire_fichier_texte_lignes(
+        &"./divers/labyrinthes/labyrinthe1.txt".to_string(),
+        None,
+    )
+    .unwrap();
+    let labyrinthe = graphes::Labyrinthe::new(&plan);
+    let (p, prec, sommet_final_opt) =
+        graphes::resoud_dijstra(&labyrinthe, labyrinthe.s_init(), labyrinthe.s_final()).unwrap();
+    assert_eq!(p[&sommet_final_opt.unwrap()], 15);
+    let chemin = graphes::reconstruit_chemin(&p, &prec, &sommet_final_opt.unwrap()).unwrap();
+    assert_eq!(chemin.sommets.len(), 15 + 1);
+    assert!(labyrinthe.s_init().contains(&chemin.sommets[0]));
+
+    // Labyrinthe ouvert généré: la distance est la distance de Manhattan entre '@' et '$'
+    let plan: Vec<String> = graphes::genere_plan_aleatoire(30, 40, 0., 1234);
+    let labyrinthe = graphes::Labyrinthe::new(&plan);
+    let (p, _prec, sommet_final_opt) =
+        graphes::resoud_dijstra(&labyrinthe, labyrinthe.s_init(), labyrinthe.s_final()).unwrap();
+    assert_eq!(p[&sommet_final_opt.unwrap()], (30 - 3) + (40 - 3));
+}
+
+#[test]
+fn test_graphes_dijkstra_cibles() {
+    let graphe = GrapheTest {};
+
+    // Arbre complet: tous les sommets accessibles, sans arrêt anticipé
+    let (p, prec) = graphes::resoud_dijstra_arbre_complet(&graphe, vec![0]).unwrap();
+    assert_eq!(p.len(), 5);
+    assert_eq!((p[&2], p[&1], p[&3], p[&4]), (3, 7, 9, 10));
+    assert_eq!(
+        graphes::reconstruit_chemin(&p, &prec, &3).unwrap().sommets,
+        vec![0, 2, 1, 3]
+    );
+
+    // Toutes les cibles, par distance croissante (les cibles inaccessibles sont absentes)
+    let (_p, _prec, cibles) =
+        graphes::resoud_dijstra_toutes_cibles(&graphe, vec![0], vec![4, 1, 3, 1]).unwrap();
+    assert_eq!(cibles, vec![(1, 7), (3, 9), (4, 10)]);
+    let (p, _prec, cibles) =
+        graphes::resoud_dijstra_toutes_cibles(&graphe, vec![3], vec![4, 0]).unwrap();
+    assert_eq!(cibles, vec![(4, 1)]);
+    assert_eq!(p.len(), 2);
+
+    // Les k cibles les plus proches: le parcours s'arrête à la k-ième
+    let (p, _prec, cibles) =
+        graphes::resoud_dijstra_k_plus_proches(&graphe, vec![0], vec![4, 3, 2], 2).unwrap();
+    assert_eq!(cibles, vec![(2, 3), (3, 9)]);
+    assert!(!p.contains_key(&4));
+    let (_p, _prec, cibles) =
+        graphes::resoud_dijstra_k_plus_proches(&graphe, vec![0], vec![4], 3).unwrap();
+    assert_eq!(cibles, vec![(4, 10)]);
+    let (p, _prec, cibles) =
+        graphes::resoud_dijstra_k_plus_proches(&graphe, vec![0], vec![4], 0).unwrap();
+    assert!(cibles.is_empty() && p.is_empty());
+
+    // Carte des distances d'un labyrinthe ouvert: distance de Manhattan à '@' pour toutes les cases
+    let plan: Vec<String> = graphes::genere_plan_aleatoire(10, 12, 0., 1234);
+    let labyrinthe = graphes::Labyrinthe::new(&plan);
+    let (p, _prec) =
+        graphes::resoud_dijstra_arbre_complet(&labyrinthe, labyrinthe.s_init()).unwrap();
+    assert_eq!(p.len(), labyrinthe.liste_sommets().len());
+    let depart = labyrinthe.s_init()[0];
+    for (pos, dist) in &p {
+        assert_eq!(*dist, graphes::distance_manhattan(depart, *pos));
+    }
+}
+
+#[test]
+fn test_graphes_a_etoile() {
+    // Avec une heuristique nulle, A* se comporte comme Dijkstra
+    let graphe = GrapheTest {};
+    let (p, _prec, sommet_final_opt) =
+        graphes::resoud_a_etoile(&graphe, vec![0], vec![4], |_: &u32| 0u32).unwrap();
+    assert_eq!(sommet_final_opt, Some(4));
+    assert_eq!(p[&4], 10);
+
+    for heuristique in [
+        graphes::Heuristique::Manhattan,
+        graphes::Heuristique::Euclidienne,
+    ] {
+        let plan: Vec<String> = fichiers::lire_fichier_texte_lignes(
+            &"./divers/labyrinthes/labyrinthe1.txt".to_string(),
+            None,
+        )
+        .unwrap();
+        let labyrinthe = graphes::Labyrinthe::new(&plan);
+        let (p_dijkstra, _prec, _sommet_final_opt) =
+            graphes::resoud_dijstra(&labyrinthe, labyrinthe.s_init(), labyrinthe.s_final())
+                .unwrap();
+        let (p, _prec, sommet_final_opt) = graphes::resoud_a_etoile(
+            &labyrinthe,
+            labyrinthe.s_init(),
+            labyrinthe.s_final(),
+            labyrinthe.heuristique(heuristique),
+        )
+        .unwrap();
+        // Heuristique admissible: même distance, et pas plus de cases parcourues
+        assert_eq!(p[&sommet_final_opt.unwrap()], 15);
+        assert!(p.len() <= p_dijkstra.len());
+
+        // La recherche gloutonne trouve un chemin, pas forcément le plus court
+        let (p, _prec, sommet_final_opt) = graphes::resoud_glouton(
+            &labyrinthe,
+            labyrinthe.s_init(),
+            labyrinthe.s_final(),
+            labyrinthe.heuristique(heuristique),
+        )
+        .unwrap();
+        assert!(p[&sommet_final_opt.unwrap()] >= 15);
+    }
+
+    // Labyrinthe ouvert: A* avec Manhattan ne parcourt que peu de cases
+    let plan: Vec<String> = graphes::genere_plan_aleatoire(30, 40, 0., 1234);
+    let labyrinthe = graphes::Labyrinthe::new(&plan);
+    let (p, _prec, sommet_final_opt) = graphes::resoud_a_etoile(
+        &labyrinthe,
+        labyrinthe.s_init(),
+        labyrinthe.s_final(),
+        labyrinthe.heuristique(graphes::Heuristique::Manhattan),
+    )
+    .unwrap();
+    assert_eq!(p[&sommet_final_opt.unwrap()], (30 - 3) + (40 - 3));
+    assert!(p.len() < (30 - 2) * (40 - 2));
+}
+
+#[test]
+fn test_graphe_pondere() {
+    // Même graphe que GrapheTest, construit par programme
+    let mut graphe: GraphePondere<u32, u32> = GraphePondere::new(true);
+    for (source, destination, poids) in [
+        (0, 1, 10),
+        (0, 2, 3),
+        (1, 3, 2),
+        (2, 1, 4),
+        (2, 3, 8),
+        (2, 4, 15),
+        (3, 4, 1),
+    ] {
+        graphe.ajoute_arete(source, destination, poids);
+    }
+    graphe.ajoute_sommet(5);
+    assert_eq!(graphe.nb_sommets(), 6);
+    assert_eq!(graphe.nb_aretes(), 7);
+    assert_eq!(graphe.liste_voisins_et_distances(&1), vec![(3, 2)]);
+    let (p, prec, _sommet_final_opt) = graphes::resoud_dijstra(&graphe, vec![0], vec![4]).unwrap();
+    let chemin = graphes::reconstruit_chemin(&p, &prec, &4).unwrap();
+    assert_eq!(chemin.sommets, vec![0, 2, 1, 3, 4]);
+
+    // Non orienté: l'arête est parcourable dans les 2 sens
+    let mut graphe_non_oriente: GraphePondere<String, f64> = GraphePondere::new(false);
+    graphe_non_oriente.ajoute_arete("a".to_string(), "b".to_string(), 1.5);
+    assert_eq!(
+        graphe_non_oriente.liste_voisins_et_distances(&"b".to_string()),
+        vec![("a".to_string(), 1.5)]
+    );
+
+    // Sauvegarde et rechargement, dans les 2 formats
+    let dossier = std::env::temp_dir();
+    let f_aretes = dossier
+        .join("algorithmes_en_rust_test_graphe.txt")
+        .to_string_lossy()
+        .into_owned();
+    graphe.sauve_liste_aretes(&f_aretes).unwrap();
+    let graphe_charge: GraphePondere<u32, u32> =
+        GraphePondere::charge_liste_aretes(&f_aretes, true).unwrap();
+    // Les sommets isolés sont écrits après les arêtes: l'ordre est conservé ici
+    assert_eq!(graphe_charge.sommets(), graphe.sommets());
+    assert_eq!(graphe_charge.aretes(), graphe.aretes());
+
+    let f_dimacs = dossier
+        .join("algorithmes_en_rust_test_graphe.gr")
+        .to_string_lossy()
+        .into_owned();
+    graphe.sauve_dimacs(&f_dimacs).unwrap();
+    let graphe_charge: GraphePondere<usize, u32> =
+        GraphePondere::charge_dimacs(&f_dimacs, true).unwrap();
+    assert_eq!(graphe_charge.nb_sommets(), 6);
+    // Sommets renumérotés à partir de 1
+    assert_eq!(graphe_charge.aretes()[0], (1, 2, 10));
+
+    // Erreur de format, avec le numéro de ligne
+    fichiers::ecrire_fichier_texte(&f_aretes, &"a b 1\na b x".to_string()).unwrap();
+    assert!(matches!(
+        GraphePondere::<String, u32>::charge_liste_aretes(&f_aretes, false),
+        Err(Erreur::FormatInvalide(_, 2, _))
+    ));
+    // Ligne 'p' d'un autre type de problème que 'sp' (ex: flot maximal)
+    fichiers::ecrire_fichier_texte(&f_dimacs, &"c Flot\np max 2 1\na 1 2 3".to_string()).unwrap();
+    assert!(matches!(
+        GraphePondere::<usize, u32>::charge_dimacs(&f_dimacs, true),
+        Err(Erreur::FormatInvalide(_, 2, _))
+    ));
+    let _ = std::fs::remove_file(&f_aretes);
+    let _ = std::fs::remove_file(&f_dimacs);
+
+    let graphe_villes: GraphePondere<String, i64> =
+        GraphePondere::charge_liste_aretes(&"./divers/graphes/villes.txt".to_string(), false)
+            .unwrap();
+    let (p, _prec, sommet_final_opt) = graphes::resoud_dijstra(
+        &graphe_villes,
+        vec!["Lille".to_string()],
+        vec!["Nice".to_string()],
+    )
+    .unwrap();
+    assert_eq!(p[&sommet_final_opt.unwrap()], 1175);
+}
+
+#[test]
+fn test_graphes_bellman_ford() {
+    // Mêmes distances que Dijkstra sur un graphe à poids positifs
+    let graphe = GrapheTest {};
+    let (p, prec) = graphes::resoud_bellman_ford(&graphe, vec![0]).unwrap();
+    assert_eq!(p[&4], 10);
+    let chemin = graphes::reconstruit_chemin(&p, &prec, &4).unwrap();
+    assert_eq!(chemin.sommets, vec![0, 2, 1, 3, 4]);
+
+    // Poids négatif (sans cycle négatif): Dijkstra refuse, Bellman-Ford trouve le plus court chemin
+    let mut graphe: GraphePondere<char, i32> = GraphePondere::new(true);
+    graphe.ajoute_arete('a', 'b', 4);
+    graphe.ajoute_arete('a', 'c', 2);
+    graphe.ajoute_arete('b', 'd', 1);
+    graphe.ajoute_arete('c', 'b', -3);
+    graphe.ajoute_arete('c', 'd', 5);
+    assert_eq!(
+        graphes::resoud_dijstra(&graphe, vec!['a'], vec!['d']),
+        Err(ErreurGraphe::PoidsNegatif('c', 'b', -3))
+    );
+    let (p, prec) = graphes::resoud_bellman_ford(&graphe, vec!['a']).unwrap();
+    let chemin = graphes::reconstruit_chemin(&p, &prec, &'d').unwrap();
+    assert_eq!(chemin.sommets, vec!['a', 'c', 'b', 'd']);
+    assert_eq!(chemin.cout, 0);
+
+    // Cycle négatif b -> d -> e -> b (poids total -1), accessible depuis 'a'
+    graphe.ajoute_arete('d', 'e', 2);
+    graphe.ajoute_arete('e', 'b', -4);
+    match (graphes::resoud_bellman_ford(&graphe, vec!['a'])) {
+        Err(ErreurGraphe::CycleNegatif(cycle)) => {
+            assert_eq!(cycle.len(), 4);
+            assert_eq!(cycle.first(), cycle.last());
+            let mut sommets_cycle: Vec<char> = cycle[1..].to_vec();
+            sommets_cycle.sort();
+            assert_eq!(sommets_cycle, vec!['b', 'd', 'e']);
+        }
+        resultat => panic!("Cycle négatif non détecté: {:?}", resultat),
+    }
+    // Le cycle est aussi accessible depuis 'c', mais pas depuis une autre composante
+    assert!(graphes::resoud_bellman_ford(&graphe, vec!['c']).is_err());
+    let mut graphe_isole: GraphePondere<char, i32> = graphe.clone();
+    graphe_isole.ajoute_arete('x', 'y', 1);
+    let (p, _prec) = graphes::resoud_bellman_ford(&graphe_isole, vec!['x']).unwrap();
+    assert_eq!(p.len(), 2);
+}
+
+#[test]
+fn test_chemins_toutes_paires() {
+    use crate::chemins_toutes_paires;
+
+    let graphe = GrapheTest {};
+    for distances in [
+        chemins_toutes_paires::floyd_warshall(&graphe).unwrap(),
+        chemins_toutes_paires::johnson(&graphe).unwrap(),
+    ] {
+        assert_eq!(
+            distances.matrice_distances()[0],
+            vec![Some(0), Some(7), Some(3), Some(9), Some(10)]
+        );
+        assert_eq!(distances.distance(&3, &0), None);
+        assert_eq!(distances.suivant(&0, &4), Some(2));
+        let chemin = distances.chemin(&0, &4).unwrap();
+        assert_eq!(chemin.sommets, vec![0, 2, 1, 3, 4]);
+        assert_eq!(chemin.cout, 10);
+        assert_eq!(distances.chemin(&4, &4).unwrap().sommets, vec![4]);
+        assert_eq!(distances.chemin(&4, &0), None);
+    }
+
+    // Voisins absents de liste_sommets: ignorés par les 2 algorithmes
+    for distances in [
+        chemins_toutes_paires::floyd_warshall(&GrapheIncomplet {}).unwrap(),
+        chemins_toutes_paires::johnson(&GrapheIncomplet {}).unwrap(),
+    ] {
+        assert_eq!(distances.distance(&0, &2), Some(5));
+        assert_eq!(distances.distance(&0, &9), None);
+    }
+
+    // Poids négatifs, sans cycle négatif: mêmes distances que Bellman-Ford depuis chaque sommet
+    let mut graphe: GraphePondere<char, i32> = GraphePondere::new(true);
+    for (source, destination, poids) in [
+        ('a', 'b', 4),
+        ('a', 'c', 2),
+        ('b', 'd', 1),
+        ('c', 'b', -3),
+        ('c', 'd', 5),
+        ('d', 'a', 1),
+    ] {
+        graphe.ajoute_arete(source, destination, poids);
+    }
+    let floyd_warshall = chemins_toutes_paires::floyd_warshall(&graphe).unwrap();
+    let johnson = chemins_toutes_paires::johnson(&graphe).unwrap();
+    for source in graphe.liste_sommets() {
+        let (p, _prec) = graphes::resoud_bellman_ford(&graphe, vec![source]).unwrap();
+        for destination in graphe.liste_sommets() {
+            let distance = p.get(&destination).copied();
+            assert_eq!(floyd_warshall.distance(&source, &destination), distance);
+            assert_eq!(johnson.distance(&source, &destination), distance);
+            assert_eq!(
+                johnson
+                    .chemin(&source, &destination)
+                    .map(|chemin| chemin.sommets),
+                floyd_warshall
+                    .chemin(&source, &destination)
+                    .map(|chemin| chemin.sommets)
+            );
+        }
+    }
+    assert_eq!(
+        johnson.chemin(&'a', &'d').unwrap().sommets,
+        vec!['a', 'c', 'b', 'd']
+    );
+
+    // Cycle négatif b -> d -> e -> b
+    graphe.ajoute_arete('d', 'e', 2);
+    graphe.ajoute_arete('e', 'b', -4);
+    for resultat in [
+        chemins_toutes_paires::floyd_warshall(&graphe),
+        chemins_toutes_paires::johnson(&graphe),
+    ] {
+        match (resultat) {
+            Err(ErreurGraphe::CycleNegatif(cycle)) => assert_eq!(cycle.first(), cycle.last()),
+            _ => panic!("Cycle négatif non détecté"),
+        }
+    }
+}
+
+#[test]
+fn test_graphes_orientes() {
+    use crate::graphes_orientes;
+
+    // Dépendances entre tâches (a -> b: a avant b)
+    let mut taches: GraphePondere<&str, u32> = GraphePondere::new(true);
+    for (avant, apres) in [
+        ("caleçon", "pantalon"),
+        ("pantalon", "chaussures"),
+        ("chaussettes", "chaussures"),
+        ("chemise", "cravate"),
+        ("pantalon", "ceinture"),
+    ] {
+        taches.ajoute_arete(avant, apres, 1);
+    }
+    taches.ajoute_sommet("montre");
+    let est_ordre_topologique = |ordre: &Vec<&str>| -> bool {
+        ordre.len() == taches.nb_sommets()
+            && taches.aretes().iter().all(|(avant, apres, _)| {
+                let position = |tache| ordre.iter().position(|t| t == tache);
+                position(avant) < position(apres)
+            })
+    };
+    let ordre_kahn = graphes_orientes::tri_topologique_kahn(&taches).unwrap();
+    assert_eq!(ordre_kahn[0..2], ["caleçon", "chaussettes"]);
+    assert!(est_ordre_topologique(&ordre_kahn));
+    assert!(est_ordre_topologique(
+        &graphes_orientes::tri_topologique_profondeur(&taches).unwrap()
+    ));
+    assert_eq!(graphes_orientes::trouve_cycle(&taches), None);
+    assert!(graphes_orientes::trouve_cycle(&GrapheTest {}).is_none());
+    assert_eq!(
+        graphes_orientes::tri_topologique_kahn(&GrapheIncomplet {}),
+        Ok(vec![0, 1, 2])
+    );
+
+    // Cycle: chaussures -> caleçon -> pantalon -> chaussures
+    taches.ajoute_arete("chaussures", "caleçon", 1);
+    let cycle = vec!["caleçon", "pantalon", "chaussures", "caleçon"];
+    assert_eq!(graphes_orientes::trouve_cycle(&taches), Some(cycle.clone()));
+    assert_eq!(
+        graphes_orientes::tri_topologique_kahn(&taches),
+        Err(ErreurGraphe::Cycle(cycle.clone()))
+    );
+    assert_eq!(
+        graphes_orientes::tri_topologique_profondeur(&taches),
+        Err(ErreurGraphe::Cycle(cycle))
+    );
+    // Boucle
+    let mut boucle: GraphePondere<u32, u32> = GraphePondere::new(true);
+    boucle.ajoute_arete(1, 1, 1);
+    assert_eq!(graphes_orientes::trouve_cycle(&boucle), Some(vec![1, 1]));
+
+    // Composantes fortement connexes: {1, 2, 3} -> {4, 5} -> {6}, et {7} isolé
+    let mut graphe: GraphePondere<u32, u32> = GraphePondere::new(true);
+    for (source, destination) in [(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 4), (5, 6)] {
+        graphe.ajoute_arete(source, destination, 1);
+    }
+    graphe.ajoute_sommet(7);
+    let mut composantes = graphes_orientes::composantes_fortement_connexes(&graphe);
+    assert_eq!(composantes.len(), 4);
+    for composante in composantes.iter_mut() {
+        composante.sort();
+    }
+    // Ordre topologique inverse
+    assert_eq!(
+        composantes,
+        vec![vec![6], vec![4, 5], vec![1, 2, 3], vec![7]]
+    );
+    // Graphe acyclique: une composante par sommet
+    assert_eq!(
+        graphes_orientes::composantes_fortement_connexes(&GrapheTest {}).len(),
+        5
+    );
+}
+
+#[test]
+fn test_flots() {
+    use crate::flots;
+
+    // Réseau classique (Cormen et al.): flot maximal de 23
+    let mut reseau: GraphePondere<&str, i32> = GraphePondere::new(true);
+    for (source, destination, capacite) in [
+        ("s", "v1", 16),
+        ("s", "v2", 13),
+        ("v1", "v3", 12),
+        ("v2", "v1", 4),
+        ("v2", "v4", 14),
+        ("v3", "v2", 9),
+        ("v3", "t", 20),
+        ("v4", "v3", 7),
+        ("v4", "t", 4),
+    ] {
+        reseau.ajoute_arete(source, destination, capacite);
+    }
+    for flot in [
+        flots::edmonds_karp(&reseau, &"s", &"t").unwrap(),
+        flots::dinic(&reseau, &"s", &"t").unwrap(),
+    ] {
+        assert_eq!(flot.valeur, 23);
+        // Capacités respectées, et conservation du flot hors source et puits
+        for ((_, _, f), (_, _, capacite)) in flot.flots.iter().zip(reseau.aretes()) {
+            assert!(0 <= *f && *f <= capacite);
+        }
+        for sommet in ["v1", "v2", "v3", "v4"] {
+            let entrant: i32 = flot
+                .flots
+                .iter()
+                .filter(|a| a.1 == sommet)
+                .map(|a| a.2)
+                .sum();
+            let sortant: i32 = flot
+                .flots
+                .iter()
+                .filter(|a| a.0 == sommet)
+                .map(|a| a.2)
+                .sum();
+            assert_eq!(entrant, sortant);
+        }
+        // Coupe minimale
+        let mut cote_source: Vec<&str> = flot.cote_source.iter().copied().collect();
+        cote_source.sort();
+        assert_eq!(cote_source, vec!["s", "v1", "v2", "v4"]);
+        assert_eq!(flot.coupe.iter().map(|a| a.2).sum::<i32>(), 23);
+    }
+
+    // Graphes aléatoires: Edmonds-Karp et Dinic trouvent la même valeur
+    let mut rng = crate::probabilites::RngMinstd::new(1234);
+    for _ in 0..20 {
+        let mut reseau: GraphePondere<usize, u32> = GraphePondere::new(true);
+        for _ in 0..40 {
+            let (source, destination) = (rng.gen_range(0..12), rng.gen_range(0..12));
+            if (source != destination) {
+                reseau.ajoute_arete(source as usize, destination as usize, rng.gen_range(0..20));
+            }
+        }
+        let flot_ek = flots::edmonds_karp(&reseau, &0, &11).unwrap();
+        let flot_dinic = flots::dinic(&reseau, &0, &11).unwrap();
+        assert_eq!(flot_ek.valeur, flot_dinic.valeur);
+        assert_eq!(
+            flot_dinic.coupe.iter().map(|a| a.2).sum::<u32>(),
+            flot_dinic.valeur
+        );
+    }
+
+    // Capacités flottantes; puits inaccessible ou absent: flot nul
+    let mut reseau: GraphePondere<u32, f64> = GraphePondere::new(true);
+    reseau.ajoute_arete(0, 1, 1.5);
+    reseau.ajoute_arete(1, 2, 0.5);
+    reseau.ajoute_arete(0, 2, 1.);
+    assert_eq!(flots::dinic(&reseau, &0, &2).unwrap().valeur, 1.5);
+    assert_eq!(flots::edmonds_karp(&reseau, &2, &0).unwrap().valeur, 0.);
+    assert_eq!(flots::edmonds_karp(&reseau, &0, &9).unwrap().valeur, 0.);
+    // Source = puits: flot nul et coupe vide (pas de panique)
+    for flot in [
+        flots::edmonds_karp(&reseau, &1, &1).unwrap(),
+        flots::dinic(&reseau, &1, &1).unwrap(),
+    ] {
+        assert_eq!(flot.valeur, 0.);
+        assert!(flot.coupe.is_empty());
+        assert!(flot.flots.iter().all(|a| a.2 == 0.));
+    }
+    reseau.ajoute_arete(2, 0, -1.);
+    assert_eq!(
+        flots::dinic(&reseau, &0, &2).err(),
+        Some(ErreurGraphe::PoidsNegatif(2, 0, -1.))
+    );
+
+    // Couplage biparti: chaque candidat obtient un poste
+    let aretes = [('a', 1), ('a', 2), ('b', 1), ('c', 2), ('c', 3)];
+    let couplage = flots::couplage_biparti(&aretes);
+    assert_eq!(couplage.len(), 3);
+    assert!(couplage.iter().all(|arete| aretes.contains(arete)));
+    assert_eq!(
+        couplage
+            .iter()
+            .map(|(g, _)| *g)
+            .collect::<std::collections::HashSet<char>>()
+            .len(),
+        3
+    );
+    assert_eq!(
+        couplage
+            .iter()
+            .map(|(_, d)| *d)
+            .collect::<std::collections::HashSet<i32>>()
+            .len(),
+        3
+    );
+    assert_eq!(flots::couplage_biparti(&[('a', 1), ('b', 1)]).len(), 1);
+}
+
+#[test]
+fn test_parcours_graphes() {
+    let graphe = GrapheTest {};
+    let largeur: Vec<(u32, usize, Option<u32>)> =
+        parcours_graphes::parcours_largeur(&graphe, vec![0])
+            .map(|s| (s.sommet, s.profondeur, s.parent))
+            .collect();
+    assert_eq!(
+        largeur,
+        vec![
+            (0, 0, None),
+            (1, 1, Some(0)),
+            (2, 1, Some(0)),
+            (3, 2, Some(1)),
+            (4, 2, Some(2))
+        ]
+    );
+    let profondeur: Vec<(u32, usize)> = parcours_graphes::parcours_profondeur(&graphe, vec![0])
+        .map(|s| (s.sommet, s.profondeur))
+        .collect();
+    assert_eq!(profondeur, vec![(0, 0), (1, 1), (3, 2), (4, 3), (2, 1)]);
+
+    let accessibles = parcours_graphes::sommets_accessibles(&graphe, vec![3]);
+    assert_eq!(accessibles.len(), 2);
+    assert!(accessibles.contains(&4));
+    assert!(parcours_graphes::est_accessible(&graphe, 0, &4));
+    assert!(!parcours_graphes::est_accessible(&graphe, 3, &0));
+
+    // 2 composantes connexes dans un graphe non orienté
+    let mut graphe: GraphePondere<u32, u32> = GraphePondere::new(false);
+    graphe.ajoute_arete(0, 1, 1);
+    graphe.ajoute_arete(2, 1, 1);
+    graphe.ajoute_arete(3, 4, 1);
+    let composantes = parcours_graphes::composantes_connexes(&graphe);
+    assert_eq!(composantes[&0], 0);
+    assert_eq!(composantes[&2], 0);
+    assert_eq!(composantes[&3], 1);
+    assert_eq!(composantes[&4], 1);
+
+    // Labyrinthe: le parcours en largeur donne la même distance que Dijkstra
+    let plan: Vec<String> = fichiers::lire_fichier_texte_lignes(
+        &"./divers/labyrinthes/labyrinthe1.txt".to_string(),
+        None,
+    )
+    .unwrap();
+    let labyrinthe = graphes::Labyrinthe::new(&plan);
+    let (p, _prec, sommet_final_opt) =
+        parcours_graphes::resoud_largeur(&labyrinthe, labyrinthe.s_init(), labyrinthe.s_final());
+    assert_eq!(p[&sommet_final_opt.unwrap()], 15);
+    let accessibles = parcours_graphes::sommets_accessibles(&labyrinthe, labyrinthe.s_init());
+    assert!(accessibles.contains(&labyrinthe.s_final()[0]));
+    assert!(accessibles.len() <= labyrinthe.liste_sommets().len());
+}
+
+#[test]
+fn test_dessin_graphes() {
+    use crate::dessin_graphes::{self, Disposition, MiseEnEvidence};
+
+    let mut graphe: GraphePondere<&str, u32> = GraphePondere::new(false);
+    graphe.ajoute_arete("a", "b", 2);
+    graphe.ajoute_arete("b", "c", 3);
+    graphe.ajoute_arete("a", "c", 7);
+    graphe.ajoute_sommet("d \"isolé\"");
+
+    // Arêtes non orientées exportées une seule fois; chemin a -> b -> c mis en évidence
+    let (p, prec, _sommet_final_opt) =
+        graphes::resoud_dijstra(&graphe, vec!["a"], vec!["c"]).unwrap();
+    let chemin = graphes::reconstruit_chemin(&p, &prec, &"c").unwrap();
+    let mise_en_evidence = MiseEnEvidence::depuis_recherche(&p, Some(&chemin));
+    let dot = dessin_graphes::exporte_dot(&graphe, false, &mise_en_evidence);
+    let lignes: Vec<&str> = dot.lines().collect();
+    assert_eq!(lignes.first(), Some(&"graph G {"));
+    assert_eq!(lignes.last(), Some(&"}"));
+    assert_eq!(lignes.iter().filter(|l| l.contains(" -- ")).count(), 3);
+    assert!(lignes.contains(&"    \"a\" [style=filled, fillcolor=orange];"));
+    assert!(lignes.contains(&"    \"d \\\"isolé\\\"\";"));
+    assert!(lignes.contains(&"    \"b\" -- \"c\" [label=\"3\", color=red, penwidth=2];"));
+    assert!(lignes.contains(&"    \"a\" -- \"c\" [label=\"7\"];"));
+
+    // Graphe orienté: un arc par voisin
+    let dot = dessin_graphes::exporte_dot(&GrapheTest {}, true, &MiseEnEvidence::new());
+    assert!(dot.starts_with("digraph G {"));
+    assert_eq!(dot.lines().filter(|l| l.contains(" -> ")).count(), 7);
+
+    // Dispositions dans le carré unité; circulaire: sommets sur le cercle de rayon 0.5
+    for disposition in [
+        Disposition::Circulaire,
+        Disposition::Forces {
+            iterations: 50,
+            seed: 1234,
+        },
+    ] {
+        let positions = dessin_graphes::calcule_disposition(&graphe, disposition);
+        assert_eq!(positions.len(), 4);
+        assert!(positions
+            .iter()
+            .all(|(x, y)| (0. ..=1.).contains(x) && (0. ..=1.).contains(y)));
+    }
+    let positions = dessin_graphes::calcule_disposition(&graphe, Disposition::Circulaire);
+    assert!(positions
+        .iter()
+        .all(|(x, y)| ((x - 0.5).hypot(y - 0.5) - 0.5).abs() < 1e-9));
+
+    let f_svg: String = std::env::temp_dir()
+        .join("algorithmes_en_rust_test_graphe.svg")
+        .to_string_lossy()
+        .to_string();
+    dessin_graphes::dessine_graphe_svg(
+        &graphe,
+        false,
+        Disposition::Circulaire,
+        &mise_en_evidence,
+        &f_svg,
+    )
+    .unwrap();
+    let svg = fichiers::lire_fichier_texte(&f_svg).unwrap();
+    assert_eq!(svg.matches("<circle").count(), 4);
+    assert_eq!(svg.matches("<line").count(), 3);
+    assert_eq!(svg.matches("stroke:red").count(), 2);
+    assert!(svg.contains(">d \"isolé\"</text>"));
+    let _ = std::fs::remove_file(&f_svg);
+}
+
+#[test]
+fn test_generateur_labyrinthes() {
+    let (hauteur, largeur) = (12, 17);
+    for algo in [
+        AlgoGeneration::RetourSurTrace,
+        AlgoGeneration::Kruskal,
+        AlgoGeneration::Prim,
+        AlgoGeneration::Wilson,
+    ] {
+        let plan = generateur_labyrinthes::genere_labyrinthe(hauteur, largeur, algo, 1234);
+        assert_eq!(plan.len(), 2 * hauteur + 1);
+        assert!(plan.iter().all(|ligne| ligne.len() == 2 * largeur + 1));
+        assert_eq!(
+            plan,
+            generateur_labyrinthes::genere_labyrinthe(hauteur, largeur, algo, 1234)
+        );
+
+        // Labyrinthe parfait: toutes les cases sont reliées, par exactement (nb_cases - 1) ouvertures
+        // => cases + ouvertures + arrivée '$' = 2 * nb_cases positions traversables, toutes accessibles
+        let labyrinthe = graphes::Labyrinthe::new(&plan);
+        let accessibles = parcours_graphes::sommets_accessibles(&labyrinthe, labyrinthe.s_init());
+        assert_eq!(labyrinthe.liste_sommets().len(), 2 * hauteur * largeur);
+        assert_eq!(accessibles.len(), 2 * hauteur * largeur);
+        assert!(accessibles.contains(&labyrinthe.s_final()[0]));
+    }
+}
+
+#[test]
+fn test_arbres_couvrants() {
+    let mut ensembles = EnsemblesDisjoints::new(5);
+    assert!(ensembles.unit(0, 1));
+    assert!(ensembles.unit(3, 4));
+    assert!(ensembles.unit(1, 4));
+    assert!(!ensembles.unit(0, 3));
+    assert_eq!(ensembles.nb_ensembles(), 2);
+    assert_eq!(ensembles.trouve(0), ensembles.trouve(4));
+    assert_ne!(ensembles.trouve(2), ensembles.trouve(0));
+
+    // Deux composantes connexes: forêt couvrante de poids minimal 1 + 2 + 4 + 7
+    let mut graphe: GraphePondere<char, i32> = GraphePondere::new(false);
+    for (source, destination, poids) in [
+        ('a', 'b', 1),
+        ('b', 'c', 2),
+        ('a', 'c', 3),
+        ('c', 'd', 4),
+        ('b', 'd', 5),
+        ('e', 'f', 7),
+    ] {
+        graphe.ajoute_arete(source, destination, poids);
+    }
+    let arbre = arbres_couvrants::kruskal(&graphe);
+    assert_eq!(arbre.poids_total, 14);
+    assert_eq!(
+        arbre.aretes,
+        vec![('a', 'b', 1), ('b', 'c', 2), ('c', 'd', 4), ('e', 'f', 7)]
+    );
+    let arbre = arbres_couvrants::prim(&graphe);
+    assert_eq!(arbre.poids_total, 14);
+    assert_eq!(arbre.aretes.len(), 4);
+
+    // Kruskal et Prim donnent le même poids sur la grille aléatoire (les arbres peuvent différer)
+    let (hauteur, largeur) = (9, 13);
+    let grille = generateur_labyrinthes::graphe_grille_aleatoire(hauteur, largeur, 1234);
+    let arbre_kruskal = arbres_couvrants::kruskal(&grille);
+    let arbre_prim = arbres_couvrants::prim(&grille);
+    assert_eq!(arbre_kruskal.poids_total, arbre_prim.poids_total);
+    assert_eq!(arbre_kruskal.aretes.len(), hauteur * largeur - 1);
+
+    // L'arbre couvrant de la grille est un labyrinthe parfait (voir test_generateur_labyrinthes)
+    for arbre in [arbre_kruskal, arbre_prim] {
+        let plan =
+            generateur_labyrinthes::plan_depuis_arbre_couvrant(hauteur, largeur, &arbre.aretes);
+        assert_eq!(plan.len(), 2 * hauteur + 1);
+        let labyrinthe = graphes::Labyrinthe::new(&plan);
+        let accessibles = parcours_graphes::sommets_accessibles(&labyrinthe, labyrinthe.s_init());
+        assert_eq!(labyrinthe.liste_sommets().len(), 2 * hauteur * largeur);
+        assert_eq!(accessibles.len(), 2 * hauteur * largeur);
+    }
+}
+
+#[test]
+fn test_labyrinthe_images() {
+    let plan: Vec<String> = vec![".-.".to_string(), "|@|".to_string(), ":$:".to_string()];
+    let dossier = std::env::temp_dir();
+    let f_svg = dossier
+        .join("algorithmes_en_rust_test_labyrinthe.svg")
+        .to_string_lossy()
+        .into_owned();
+    let f_bmp = dossier
+        .join("algorithmes_en_rust_test_labyrinthe.bmp")
+        .to_string_lossy()
+        .into_owned();
+
+    graphes::dessine_labyrinthe_svg(&plan, &f_svg).unwrap();
+    let svg: String = fichiers::lire_fichier_texte(&f_svg).unwrap();
+    // Fond + 9 caractères non vides
+    assert_eq!(svg.matches("<rect").count(), 1 + 9);
+
+    graphes::dessine_labyrinthe_bmp(&plan, &f_bmp).unwrap();
+    let bmp: Vec<u8> = fichiers::lire_fichier_binaire(&f_bmp).unwrap();
+    // Image de 30x30 pixels: lignes de 90 octets, complétées à 92
+    assert_eq!(&bmp[0..2], b"BM");
+    assert_eq!(bmp.len(), 54 + 92 * 30);
+    assert_eq!(u32::from_le_bytes([bmp[18], bmp[19], bmp[20], bmp[21]]), 30);
+
+    let _ = std::fs::remove_file(&f_svg);
+    let _ = std::fs::remove_file(&f_bmp);
+}
+
+// Distance du plus court chemin dans un plan, avec la légende et les diagonales données
+fn distance_plan(plan: &[&str], legende: graphes::Legende, diagonales: bool) -> Option<u64> {
+    let plan: Vec<String> = plan.iter().map(|ligne| ligne.to_string()).collect();
+    let labyrinthe = graphes::Labyrinthe::avec_legende(&plan, legende, diagonales);
+    let (p, _prec, sommet_final_opt) =
+        graphes::resoud_dijstra(&labyrinthe, labyrinthe.s_init(), labyrinthe.s_final()).unwrap();
+    sommet_final_opt.map(|sommet_final| p[&sommet_final])
+}
+
+// Coût réel du chemin trouvé par le parcours en largeur (moins d'arêtes, pas forcément le moins cher)
+fn cout_largeur_plan(plan: &[&str], legende: graphes::Legende, diagonales: bool) -> Option<u64> {
+    let plan: Vec<String> = plan.iter().map(|ligne| ligne.to_string()).collect();
+    let labyrinthe = graphes::Labyrinthe::avec_legende(&plan, legende, diagonales);
+    let (p, _prec, sommet_final_opt) = parcours_graphes::resoud_largeur_avec_couts(
+        &labyrinthe,
+        labyrinthe.s_init(),
+        labyrinthe.s_final(),
+    );
+    sommet_final_opt.map(|sommet_final| p[&sommet_final])
+}
+
+#[test]
+fn test_labyrinthe_legende_et_diagonales() {
+    use graphes::{Legende, COUT_DIAGONAL, COUT_ORTHOGONAL_DIAGONALES};
+
+    // Terrain: le détour par les cases de coût 1 est moins cher que la case de coût 9
+    let plan = ["#####", "#@9$#", "#111#", "#####"];
+    assert_eq!(distance_plan(&plan, Legende::terrain(), false), Some(4));
+    // Le parcours en largeur passe par la case de coût 9, et son coût doit être le vrai (pas 2 étapes)
+    assert_eq!(
+        cout_largeur_plan(&plan, Legende::terrain(), false),
+        Some(10)
+    );
+    // Légende standard: les chiffres sont des murs
+    assert_eq!(distance_plan(&plan, Legende::standard(), false), None);
+    // Légende personnalisée
+    let mut legende = Legende::standard();
+    legende.definit_cout('9', 2);
+    legende.definit_cout('1', 5);
+    assert_eq!(distance_plan(&plan, legende, false), Some(3));
+
+    // Sens uniques: on ne sort d'une flèche que dans sa direction
+    assert_eq!(
+        distance_plan(&["#####", "#@>$#", "#####"], Legende::terrain(), false),
+        Some(2)
+    );
+    assert_eq!(
+        distance_plan(&["#####", "#@<$#", "#####"], Legende::terrain(), false),
+        None
+    );
+
+    // Diagonales
+    let plan = ["#####", "#@  #", "#   #", "#  $#", "#####"];
+    assert_eq!(distance_plan(&plan, Legende::standard(), false), Some(4));
+    assert_eq!(
+        distance_plan(&plan, Legende::standard(), true),
+        Some(2 * COUT_DIAGONAL)
+    );
+    assert_eq!(
+        cout_largeur_plan(&plan, Legende::standard(), true),
+        Some(2 * COUT_DIAGONAL)
+    );
+    // Pas de diagonale en coupant le coin d'un mur
+    let plan = ["####", "#@##", "# $#", "####"];
+    assert_eq!(
+        distance_plan(&plan, Legende::standard(), true),
+        Some(2 * COUT_ORTHOGONAL_DIAGONALES)
+    );
+
+    // A* avec l'heuristique octile reste optimal avec les diagonales
+    let plan: Vec<String> = graphes::genere_plan_aleatoire(30, 40, 0.2, 1234);
+    let labyrinthe = graphes::Labyrinthe::avec_legende(&plan, Legende::standard(), true);
+    let (p_dijkstra, _prec, sommet_final_opt) =
+        graphes::resoud_dijstra(&labyrinthe, labyrinthe.s_init(), labyrinthe.s_final()).unwrap();
+    let distance_dijkstra = p_dijkstra[&sommet_final_opt.unwrap()];
+    for heuristique in [
+        graphes::Heuristique::Octile,
+        graphes::Heuristique::Euclidienne,
+    ] {
+        let (p, _prec, sommet_final_opt) = graphes::resoud_a_etoile(
+            &labyrinthe,
+            labyrinthe.s_init(),
+            labyrinthe.s_final(),
+            labyrinthe.heuristique(heuristique),
+        )
+        .unwrap();
+        assert_eq!(p[&sommet_final_opt.unwrap()], distance_dijkstra);
+        assert!(p.len() <= p_dijkstra.len());
+    }
+}
+
+#[test]
+fn test_labyrinthe_positions() {
+    use graphes::Position;
+
+    // Ordre de lecture du plan: ligne, puis colonne
+    assert!(Position::new(0, 5) < Position::new(1, 0));
+    assert!(Position::new(1, 2) < Position::new(1, 3));
+    assert_eq!(Position::new(2, 3).to_string(), "(ligne 2, colonne 3)");
+    assert_eq!(Position::new(0, 0).decale(-1, 0), None);
+    assert_eq!(Position::new(1, 1).decale(1, -1), Some(Position::new(2, 0)));
+
+    // Cases traversables au bord du plan: pas de voisin en dehors du plan
+    let plan: Vec<String> = vec!["@ ".to_string(), " $".to_string()];
+    let labyrinthe = graphes::Labyrinthe::new(&plan);
+    assert_eq!(labyrinthe.s_init(), vec![Position::new(0, 0)]);
+    assert_eq!(labyrinthe.s_final(), vec![Position::new(1, 1)]);
+    let mut voisins: Vec<Position> = labyrinthe
+        .liste_voisins_et_distances(&Position::new(0, 0))
+        .into_iter()
+        .map(|(pos, _dist)| pos)
+        .collect();
+    voisins.sort();
+    assert_eq!(voisins, vec![Position::new(0, 1), Position::new(1, 0)]);
+    assert_eq!(
+        labyrinthe
+            .liste_voisins_et_distances(&Position::new(1, 1))
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_tri_rapide() {
+    // Tableaux aléatoires (avec ou sans doublons), déjà triés, inversés, constants
+    let mut rng = RngMinstd::new(1234);
+    let mut tableaux: Vec<Vec<i32>> = vec![
+        vec![],
+        vec![7],
+        (0..200).collect(),
+        (0..200).rev().collect(),
+    ];
+    tableaux.push(vec![5; 100]);
+    for n in [2, 3, 17, 100, 1000] {
+        tableaux.push(
+            (0..n)
+                .map(|_| rng.gen_range(0..1000) as i32 - 500)
+                .collect(),
+        );
+        tableaux.push((0..n).map(|_| rng.gen_range(0..4) as i32).collect());
+    }
+
+    for partition in [
+        tri::Partition::Lomuto,
+        tri::Partition::Hoare,
+        tri::Partition::TroisVoies,
+    ] {
+        for pivot in [
+            tri::ChoixPivot::MedianeDeTrois,
+            tri::ChoixPivot::Aleatoire { seed: 42 },
+        ] {
+            for seuil_insertion in [0, 16] {
+                let parametres = tri::ParametresTriRapide {
+                    partition,
+                    pivot,
+                    seuil_insertion,
+                };
+                for tableau in tableaux.iter() {
+                    let mut attendu: Vec<i32> = tableau.clone();
+                    attendu.sort();
+                    let mut trie: Vec<i32> = tableau.clone();
+                    tri::tri_rapide_parametre(&mut trie, &parametres, i32::cmp);
+                    assert_eq!(trie, attendu, "{:?}", parametres);
+                }
+            }
+        }
+    }
+
+    // Générique: chaines de caractères, et ordre décroissant par comparateur
+    let mut langages: Vec<String> = ["rust", "go", "shell", "ruby", "python", "c"]
+        .iter()
+        .map(|langage| langage.to_string())
+        .collect();
+    tri::tri_rapide(&mut langages);
+    assert_eq!(langages, ["c", "go", "python", "ruby", "rust", "shell"]);
+    tri::tri_rapide_avec_comparaison(&mut langages, |a, b| b.cmp(a));
+    assert_eq!(langages, ["shell", "rust", "ruby", "python", "go", "c"]);
+}
+
+#[test]
+fn test_tri_comparaison_et_cle() {
+    // Enregistrements (nom, age), triés par age: les tris stables gardent l'ordre des noms à age égal
+    type Personne = (&'static str, u32);
+    type TriPersonnes = fn(&mut [Personne]);
+    let personnes: Vec<Personne> = vec![
+        ("Alice", 34),
+        ("Bob", 25),
+        ("Chloé", 34),
+        ("David", 19),
+        ("Emma", 25),
+        ("Farid", 34),
+    ];
+    let mut par_age: Vec<Personne> = personnes.clone();
+    par_age.sort_by_key(|personne| personne.1);
+    let mut par_nom_decroissant: Vec<Personne> = personnes.clone();
+    par_nom_decroissant.sort_by(|a, b| b.0.cmp(a.0));
+
+    let tris_stables_par_cle: [TriPersonnes; 2] = [
+        |t| tri_variantes::tri_par_insertion_generique_par_cle(t, |personne| personne.1),
+        |t| tri_variantes::tri_fusion_par_cle(t, |personne| personne.1),
+    ];
+    for tri_par_cle in tris_stables_par_cle {
+        let mut trie = personnes.clone();
+        tri_par_cle(&mut trie);
+        assert_eq!(trie, par_age);
+    }
+
+    let tris_par_cle: [TriPersonnes; 3] = [
+        |t| tri_variantes::tri_par_selection_par_cle(t, |personne| personne.1),
+        |t| tri::tri_rapide_par_cle(t, |personne| personne.1),
+        |t| tri::tri_par_tas_par_cle(t, |personne| personne.1),
+    ];
+    for tri_par_cle in tris_par_cle {
+        let mut trie = personnes.clone();
+        tri_par_cle(&mut trie);
+        let ages: Vec<u32> = trie.iter().map(|personne| personne.1).collect();
+        assert_eq!(ages, [19, 25, 25, 34, 34, 34]);
+    }
+
+    let tris_avec_comparaison: [TriPersonnes; 5] = [
+        |t| tri_variantes::tri_par_insertion_generique_avec_comparaison(t, |a, b| b.0.cmp(a.0)),
+        |t| tri_variantes::tri_par_selection_avec_comparaison(t, |a, b| b.0.cmp(a.0)),
+        |t| tri_variantes::tri_fusion_avec_comparaison(t, |a, b| b.0.cmp(a.0)),
+        |t| tri::tri_rapide_avec_comparaison(t, |a, b| b.0.cmp(a.0)),
+        |t| tri::tri_par_tas_avec_comparaison(t, |a, b| b.0.cmp(a.0)),
+    ];
+    for tri_avec_comparaison in tris_avec_comparaison {
+        let mut trie = personnes.clone();
+        tri_avec_comparaison(&mut trie);
+        assert_eq!(trie, par_nom_decroissant);
+    }
+
+    // Tableaux aléatoires plus grands, pour le tas et la fusion
+    let mut rng = RngMinstd::new(1234);
+    for n in [0, 1, 2, 5, 100, 1000] {
+        let tableau: Vec<u32> = (0..n).map(|_| rng.gen_range(0..50)).collect();
+        let mut attendu = tableau.clone();
+        attendu.sort_by(|a, b| b.cmp(a));
+        let mut trie = tableau.clone();
+        tri::tri_par_tas_avec_comparaison(&mut trie, |a, b| b.cmp(a));
+        assert_eq!(trie, attendu);
+        let mut trie = tableau.clone();
+        tri_variantes::tri_fusion_avec_comparaison(&mut trie, |a, b| b.cmp(a));
+        assert_eq!(trie, attendu);
+    }
+}
+
+#[test]
+fn test_tri_fusion_ameliore() {
+    let mut rng = RngMinstd::new(1234);
+    for n in [0, 1, 2, 3, 4, 5, 7, 8, 9, 100, 1000, 1025] {
+        let tableau: Vec<i32> = (0..n).map(|_| rng.gen_range(0..100) as i32 - 50).collect();
+        let mut attendu: Vec<i32> = tableau.clone();
+        attendu.sort();
+
+        let mut trie: Vec<i32> = tableau.clone();
+        tri_variantes::tri_fusion_ameliore(&mut trie);
+        assert_eq!(trie, attendu);
+        let mut trie: Vec<i32> = tableau.clone();
+        tri_variantes::tri_fusion_ascendant(&mut trie);
+        assert_eq!(trie, attendu);
+    }
+
+    // Stabilité: tri des (clé, position d'origine) selon la clé seule
+    let tableau: Vec<(u32, usize)> = (0..500).map(|i| (rng.gen_range(0..10), i)).collect();
+    let mut attendu: Vec<(u32, usize)> = tableau.clone();
+    attendu.sort_by_key(|a| std::cmp::Reverse(a.0));
+    let mut trie = tableau.clone();
+    tri_variantes::tri_fusion_ameliore_avec_comparaison(&mut trie, |a, b| b.0.cmp(&a.0));
+    assert_eq!(trie, attendu);
+    let mut trie = tableau.clone();
+    tri_variantes::tri_fusion_ascendant_avec_comparaison(&mut trie, |a, b| b.0.cmp(&a.0));
+    assert_eq!(trie, attendu);
+}
+
+#[test]
+fn test_tri_sans_comparaison() {
+    let mut rng = RngMinstd::new(1234);
+    for n in [0, 1, 2, 31, 33, 1000] {
+        // Entiers non signés et signés (toute l'étendue du type), et petite étendue pour le comptage
+        let non_signes: Vec<u32> = (0..n).map(|_| rng.gen() << 1 | rng.gen() & 1).collect();
+        let signes: Vec<i64> = (0..n)
+            .map(|_| ((rng.gen() as i64) << 33) - ((rng.gen() as i64) << 2))
+            .collect();
+        let petits: Vec<i16> = (0..n).map(|_| rng.gen_range(0..100) as i16 - 50).collect();
+
+        let mut attendu = non_signes.clone();
+        attendu.sort();
+        for tri_radix in [
+            tri_sans_comparaison::tri_radix_lsd,
+            tri_sans_comparaison::tri_radix_msd,
+        ] {
+            let mut trie = non_signes.clone();
+            tri_radix(&mut trie);
+            assert_eq!(trie, attendu);
+        }
+        let mut attendu = signes.clone();
+        attendu.sort();
+        for tri_radix in [
+            tri_sans_comparaison::tri_radix_lsd,
+            tri_sans_comparaison::tri_radix_msd,
+        ] {
+            let mut trie = signes.clone();
+            tri_radix(&mut trie);
+            assert_eq!(trie, attendu);
+        }
+        let mut attendu = petits.clone();
+        attendu.sort();
+        let mut trie = petits.clone();
+        tri_sans_comparaison::tri_comptage(&mut trie);
+        assert_eq!(trie, attendu);
+
+        // Chaines de longueurs variées, avec des préfixes communs
+        let mots: Vec<String> = (0..n)
+            .map(|_| {
+                let longueur = rng.gen_range(0..8) as usize;
+                (0..longueur)
+                    .map(|_| (b'a' + rng.gen_range(0..3) as u8) as char)
+                    .collect()
+            })
+            .collect();
+        let mut attendu = mots.clone();
+        attendu.sort();
+        let mut trie = mots.clone();
+        tri_sans_comparaison::tri_radix_lsd_octets(&mut trie);
+        assert_eq!(trie, attendu);
+        let mut trie = mots.clone();
+        tri_sans_comparaison::tri_radix_msd_octets(&mut trie);
+        assert_eq!(trie, attendu);
+
+        // Flottants suivant une loi normale
+        let normales: Vec<f64> = probabilites::box_muller(n, 42);
+        let mut attendu = normales.clone();
+        attendu.sort_by(f64::total_cmp);
+        let mut trie = normales.clone();
+        tri_sans_comparaison::tri_par_paquets(&mut trie);
+        assert_eq!(trie, attendu);
+    }
+
+    // Comptage stable par clé: (clé, position d'origine)
+    let paires: Vec<(usize, usize)> = (0..200)
+        .map(|i| (rng.gen_range(0..5) as usize, i))
+        .collect();
+    let mut attendu = paires.clone();
+    attendu.sort_by_key(|paire| paire.0);
+    let mut trie = paires.clone();
+    tri_sans_comparaison::tri_comptage_par_cle(&mut trie, 5, |paire| paire.0);
+    assert_eq!(trie, attendu);
+
+    let mut valeurs_egales: Vec<f64> = vec![1.5; 10];
+    tri_sans_comparaison::tri_par_paquets(&mut valeurs_egales);
+    assert_eq!(valeurs_egales, vec![1.5; 10]);
+}
+
+#[test]
+fn test_tri_introspectif_et_timsort() {
+    // Tableaux aléatoires, et motifs favorables à TimSort ou défavorables au tri rapide
+    let mut rng = RngMinstd::new(1234);
+    let mut tableaux: Vec<Vec<i32>> = vec![
+        vec![],
+        vec![3],
+        (0..1000).collect(),
+        (0..1000).rev().collect(),
+    ];
+    tableaux.push((0..1000).map(|i| i % 37).collect()); // dents de scie
+    tableaux.push((0..500).chain((0..500).rev()).collect()); // tuyau d'orgue
+    tableaux.push(
+        (0..1000)
+            .map(|i| if (i % 100 == 0) { -i } else { i })
+            .collect(),
+    );
+    for n in [2, 15, 17, 63, 65, 1000, 5000] {
+        tableaux.push((0..n).map(|_| rng.gen_range(0..1000) as i32).collect());
+    }
+    for tableau in tableaux.iter() {
+        let mut attendu: Vec<i32> = tableau.clone();
+        attendu.sort();
+        let mut trie: Vec<i32> = tableau.clone();
+        tri::tri_introspectif(&mut trie);
+        assert_eq!(trie, attendu);
+        let mut trie: Vec<i32> = tableau.clone();
+        tri_variantes::tri_timsort(&mut trie);
+        assert_eq!(trie, attendu);
+    }
+
+    // TimSort est stable: (clé, position d'origine), avec des séquences décroissantes contenant des égalités
+    let paires: Vec<(i32, usize)> = (0..3000)
+        .map(|i| (((3000 - i) / 7) as i32 + rng.gen_range(0..3) as i32, i))
+        .collect();
+    let mut attendu = paires.clone();
+    attendu.sort_by_key(|paire| paire.0);
+    let mut trie = paires.clone();
+    tri_variantes::tri_timsort_avec_comparaison(&mut trie, |a, b| a.0.cmp(&b.0));
+    assert_eq!(trie, attendu);
+
+    let mut trie = paires.clone();
+    tri::tri_introspectif_avec_comparaison(&mut trie, |a, b| b.cmp(a));
+    let mut attendu = paires.clone();
+    attendu.sort_by(|a, b| b.cmp(a));
+    assert_eq!(trie, attendu);
+}
+
+#[test]
+fn test_tris_paralleles() {
+    let mut rng = RngMinstd::new(1234);
+    for n in [0, 1, 2, 3, 101, 5000] {
+        let tableau: Vec<i32> = (0..n).map(|_| rng.gen_range(0..1000) as i32).collect();
+        let mut attendu: Vec<i32> = tableau.clone();
+        attendu.sort();
+        for nb_threads in [1, 2, 3, 8] {
+            for seuil_sequentiel in [1, 50, 10000] {
+                let parametres = tri::ParametresTriParallele {
+                    nb_threads,
+                    seuil_sequentiel,
+                };
+                let mut trie: Vec<i32> = tableau.clone();
+                tri::tri_rapide_parallele(&mut trie, &parametres);
+                assert_eq!(trie, attendu, "{:?}", parametres);
+                let mut trie: Vec<i32> = tableau.clone();
+                tri::tri_fusion_parallele(&mut trie, &parametres);
+                assert_eq!(trie, attendu, "{:?}", parametres);
+            }
+        }
+    }
+
+    // Générique, par comparateur; le tri fusion parallèle reste stable
+    let parametres = tri::ParametresTriParallele {
+        nb_threads: 4,
+        seuil_sequentiel: 20,
+    };
+    let mut mots: Vec<String> = (0..300)
+        .map(|i| format!("mot{}", (i * 7919) % 300))
+        .collect();
+    let mut attendu = mots.clone();
+    attendu.sort_by(|a, b| b.cmp(a));
+    tri::tri_rapide_parallele_avec_comparaison(&mut mots, &parametres, |a, b| b.cmp(a));
+    assert_eq!(mots, attendu);
+
+    let paires: Vec<(u32, usize)> = (0..1000).map(|i| (rng.gen_range(0..10), i)).collect();
+    let mut attendu = paires.clone();
+    attendu.sort_by_key(|paire| paire.0);
+    let mut trie = paires.clone();
+    tri::tri_fusion_parallele_avec_comparaison(&mut trie, &parametres, |a, b| a.0.cmp(&b.0));
+    assert_eq!(trie, attendu);
+}
+
+#[test]
+fn test_cli() {
+    const SPEC: SpecCommande = SpecCommande {
+        nom: "essai",
+        resume: "Commande de test",
+        usage: "<n> [fichier] [--seed S] [--images]",
+        nb_positionnels_min: 1,
+        nb_positionnels_max: 2,
+        options: &[
+            SpecOption {
+                nom: "seed",
+                avec_valeur: true,
+                aide: "Graine",
+            },
+            SpecOption {
+                nom: "images",
+                avec_valeur: false,
+                aide: "Dessine",
+            },
+        ],
+    };
+    let analyse = |args: &[&str]| -> Result<Analyse, String> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        cli::analyse_arguments(&SPEC, &args)
+    };
+    let arguments = |args: &[&str]| -> cli::ArgumentsCommande {
+        match (analyse(args)) {
+            Ok(Analyse::Arguments(arguments)) => arguments,
+            _ => panic!("Analyse de {:?} en échec", args),
+        }
+    };
+
+    // Positionnels, option avec valeur (y compris négative, ou en syntaxe --nom=valeur) et drapeau
+    let args = arguments(&["12", "--seed", "-5", "sortie.txt", "--images"]);
+    assert_eq!(args.nb_positionnels(), 2);
+    assert_eq!(args.positionnel::<u32>(0, "n"), Ok(12));
+    assert_eq!(
+        args.positionnel_ou(1, "fichier", String::from("defaut.txt")),
+        Ok(String::from("sortie.txt"))
+    );
+    assert_eq!(args.option::<i64>("seed"), Ok(Some(-5)));
+    assert!(args.drapeau("images"));
+    assert_eq!(arguments(&["3", "--seed=7"]).option_ou("seed", 0u32), Ok(7));
+
+    // Valeurs par défaut
+    let args = arguments(&["3"]);
+    assert_eq!(args.positionnel_ou(1, "fichier", 0u8), Ok(0));
+    assert_eq!(args.option::<u32>("seed"), Ok(None));
+    assert_eq!(args.option_ou("seed", 42u32), Ok(42));
+    assert!(!args.drapeau("images"));
+
+    // Valeurs mal typées: erreur (pas de panique)
+    let args = arguments(&["abc", "--seed", "xyz"]);
+    assert!(args.positionnel::<u32>(0, "n").is_err());
+    assert!(args.option_ou("seed", 0u32).is_err());
+
+    // Erreurs d'analyse
+    let erreur = |args: &[&str]| -> String {
+        match (analyse(args)) {
+            Err(message) => message,
+            _ => panic!("Analyse de {:?} acceptée à tort", args),
+        }
+    };
+    assert!(erreur(&["1", "--inconnue"]).contains("Option inconnue"));
+    assert!(erreur(&["1", "--seed"]).contains("Valeur manquante"));
+    assert!(erreur(&["1", "--images=oui"]).contains("n'attend pas de valeur"));
+    assert!(erreur(&[]).contains("Pas assez d'arguments"));
+    assert!(erreur(&["1", "2", "3"]).contains("Trop d'arguments"));
+    assert!(matches!(analyse(&["--help"]), Ok(Analyse::Aide)));
+}
@@ -1,5 +1,5 @@
 // Fichier module contenant les implémentations des tris standard
-// sans généricité, pour les tableaux d'entier i32 (excepté pour le tri par tas)
+// sans généricité, pour les tableaux d'entier i32 (excepté pour le tri rapide et le tri par tas)
 // Le fichier algos_tri_variantes.rs contient des exemples d'algos de tri génériques.
 
 // Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
@@ -7,6 +7,14 @@
 // Ne pas faire de warning si des fonctions ne sont pas appelées
 #![allow(dead_code)]
 
+use std::cmp::Ordering;
+
+use crate::probabilites::RngMinstd;
+use crate::tri_variantes;
+
+// Les sous-tableaux d'au plus SEUIL_INSERTION_INTROSPECTIF éléments du tri introspectif sont triés par insertion
+const SEUIL_INSERTION_INTROSPECTIF: usize = 16;
+
 // Algorithme du tri par insertion
 // Entrée = Sortie:
 // mon_tableau: tableau d'entiers 'mon_tableau'
@@ -91,57 +99,309 @@ pub fn tri_par_selection(mon_tableau: &mut [i32]) {
     }
 } // fn tri_par_selection ()
 
+// Méthode de partition du tri rapide
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Partition {
+    // Lomuto: un seul indice parcourt le tableau. Simple, mais quadratique s'il y a beaucoup de doublons
+    Lomuto,
+    // Hoare: deux indices se rapprochent depuis les extrémités. Moins d'échanges,
+    // et les doublons sont répartis des deux côtés du pivot
+    Hoare,
+    // Trois voies (drapeau hollandais de Dijkstra): éléments plus petits / égaux / plus grands que le pivot.
+    // Les éléments égaux au pivot ne sont plus triés: linéaire si toutes les valeurs sont égales
+    TroisVoies,
+}
+
+// Choix du pivot du tri rapide
+// (prendre toujours le premier élément rendrait le tri quadratique sur un tableau déjà trié)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChoixPivot {
+    // Médiane du premier, du dernier et de l'élément du milieu
+    MedianeDeTrois,
+    // Elément tiré au hasard par le générateur MINSTD, initialisé avec la seed (non nulle)
+    Aleatoire { seed: u32 },
+}
+
+// Paramètres du tri rapide
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParametresTriRapide {
+    pub partition: Partition,
+    pub pivot: ChoixPivot,
+    // Les sous-tableaux d'au plus 'seuil_insertion' éléments sont triés par insertion
+    // (plus rapide sur les petits tableaux). 0 ou 1: tri rapide pur
+    pub seuil_insertion: usize,
+}
+
+impl Default for ParametresTriRapide {
+    fn default() -> Self {
+        ParametresTriRapide {
+            partition: Partition::Hoare,
+            pivot: ChoixPivot::MedianeDeTrois,
+            seuil_insertion: 16,
+        }
+    }
+}
+
 // Algorithme du tri rapide
 // Entrée = Sortie:
-// mon_tableau: tableau d'entiers 'mon_tableau'
-// Implémentation tri en place, non stable
-// Complexité: n.log(n), en moyenne, et n^2 dans le pire cas
-// Version standard, non optimisée: Allocation interne à chaque appel pour construire les sous-tableaus
+// mon_tableau: tableau d'éléments ordonnés (trait Ord)
+// Implémentation tri en place (aucune allocation), non stable
+// Complexité: n.log(n), en moyenne, et n^2 dans le pire cas (très improbable avec les pivots choisis)
+// Paramètres par défaut: partition de Hoare, pivot médiane de trois, insertion sous 16 éléments
 // Voir: https://fr.wikipedia.org/wiki/Tri_rapide
-pub fn tri_rapide(mon_tableau: &mut [i32]) {
-    let n = mon_tableau.len();
+pub fn tri_rapide<T: Ord>(mon_tableau: &mut [T]) {
+    tri_rapide_avec_comparaison(mon_tableau, T::cmp);
+}
 
-    // Gestion des cas particuliers (fin des appels récursifs)
-    // tableau avec 1 seul élément (-> Plus rien à trier)
-    if n <= 1 {
-        return;
-    }
+// Tri rapide selon une fonction de comparaison (ex: |a, b| b.cmp(a) pour un tri décroissant)
+pub fn tri_rapide_avec_comparaison<T, F>(mon_tableau: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    tri_rapide_parametre(mon_tableau, &ParametresTriRapide::default(), compare);
+}
 
-    // Cas général, menant à 2 appels récursifs pour trier les 2 sous-tableaus
+// Tri rapide selon une clé extraite de chaque élément (ex: |personne| personne.age)
+pub fn tri_rapide_par_cle<T, K, F>(mon_tableau: &mut [T], mut cle: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    tri_rapide_avec_comparaison(mon_tableau, |a: &T, b: &T| cle(a).cmp(&cle(b)));
+}
+
+// Tri rapide, avec le choix de la partition, du pivot et du seuil du tri par insertion
+pub fn tri_rapide_parametre<T, F>(
+    mon_tableau: &mut [T],
+    parametres: &ParametresTriRapide,
+    mut compare: F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut rng_opt: Option<RngMinstd> = match (parametres.pivot) {
+        ChoixPivot::MedianeDeTrois => None,
+        ChoixPivot::Aleatoire { seed } => Some(RngMinstd::new(seed)),
+    };
+    tri_rapide_recursif(mon_tableau, parametres, &mut rng_opt, &mut compare);
+}
 
-    let pivot = mon_tableau[0]; // Existe car le tableau a au moins 1 éléments
+fn tri_rapide_recursif<T, F>(
+    mon_tableau: &mut [T],
+    parametres: &ParametresTriRapide,
+    rng_opt: &mut Option<RngMinstd>,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut mon_tableau = mon_tableau;
+    // Boucle sur le plus grand des 2 sous-tableaux, et appel récursif sur le plus petit:
+    // la profondeur de récursion reste en log(n), même dans le pire cas
+    loop {
+        let n = mon_tableau.len();
+        if (n <= 1) {
+            return;
+        }
+        if (n <= parametres.seuil_insertion) {
+            tri_variantes::tri_par_insertion_generique_avec_comparaison(mon_tableau, &mut *compare);
+            return;
+        }
 
-    let mut mon_tableau_gauche_vec: Vec<i32> = Vec::new();
-    let mut mon_tableau_droite_vec: Vec<i32> = Vec::new();
+        let index_pivot = match (rng_opt) {
+            Some(rng) => rng.gen_range(0..n) as usize,
+            None => index_mediane_de_trois(mon_tableau, compare),
+        };
 
-    for elem in mon_tableau.iter().skip(1) {
-        if (*elem <= pivot) {
-            mon_tableau_gauche_vec.push(*elem);
+        // Après la partition, les éléments d'index dans debut_egaux..fin_egaux sont à leur place finale
+        let (debut_egaux, fin_egaux) = match (parametres.partition) {
+            Partition::Lomuto => {
+                let index = partition_lomuto(mon_tableau, index_pivot, compare);
+                (index, index + 1)
+            }
+            Partition::Hoare => {
+                let index = partition_hoare(mon_tableau, index_pivot, compare);
+                (index, index + 1)
+            }
+            Partition::TroisVoies => partition_trois_voies(mon_tableau, index_pivot, compare),
+        };
+
+        let (gauche, reste) = mon_tableau.split_at_mut(debut_egaux);
+        let droite = &mut reste[(fin_egaux - debut_egaux)..];
+        if (gauche.len() < droite.len()) {
+            tri_rapide_recursif(gauche, parametres, rng_opt, compare);
+            mon_tableau = droite;
+        } else {
+            tri_rapide_recursif(droite, parametres, rng_opt, compare);
+            mon_tableau = gauche;
         }
-        if (*elem > pivot) {
-            mon_tableau_droite_vec.push(*elem);
+    }
+}
+
+// Tri introspectif (introsort, de Musser): tri rapide (partition de Hoare, pivot médiane de trois),
+// qui bascule sur le tri par tas quand la profondeur de récursion dépasse 2.log2(n),
+// signe que les pivots sont mauvais (tableau construit pour piéger la médiane de trois par ex)
+// Les petits sous-tableaux sont triés par insertion
+// Le tri par tas utilisé est la version en place (tri_par_tas_avec_comparaison) de tri_par_tas_generique:
+// pas d'allocation, et pas de contrainte Clone sur les éléments
+// https://fr.wikipedia.org/wiki/Introsort
+// En place, non stable. Complexité: n.log(n), en moyenne et dans le pire cas
+pub fn tri_introspectif<T: Ord>(mon_tableau: &mut [T]) {
+    tri_introspectif_avec_comparaison(mon_tableau, T::cmp);
+}
+
+// Tri introspectif selon une fonction de comparaison 'compare'
+pub fn tri_introspectif_avec_comparaison<T, F>(mon_tableau: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // 2 x (nombre de bits de n), soit environ 2.log2(n)
+    let profondeur_max = 2 * (usize::BITS - mon_tableau.len().leading_zeros()) as usize;
+    tri_introspectif_recursif(mon_tableau, profondeur_max, &mut compare);
+}
+
+fn tri_introspectif_recursif<T, F>(mon_tableau: &mut [T], profondeur_max: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut mon_tableau = mon_tableau;
+    let mut profondeur_max = profondeur_max;
+    loop {
+        if (mon_tableau.len() <= SEUIL_INSERTION_INTROSPECTIF) {
+            tri_variantes::tri_par_insertion_generique_avec_comparaison(mon_tableau, &mut *compare);
+            return;
+        }
+        if (profondeur_max == 0) {
+            tri_par_tas_avec_comparaison(mon_tableau, &mut *compare);
+            return;
+        }
+        profondeur_max -= 1;
+
+        let index_pivot = index_mediane_de_trois(mon_tableau, compare);
+        let index = partition_hoare(mon_tableau, index_pivot, compare);
+
+        // Appel récursif sur le plus petit sous-tableau, boucle sur le plus grand
+        let (gauche, reste) = mon_tableau.split_at_mut(index);
+        let droite = &mut reste[1..];
+        if (gauche.len() < droite.len()) {
+            tri_introspectif_recursif(gauche, profondeur_max, compare);
+            mon_tableau = droite;
+        } else {
+            tri_introspectif_recursif(droite, profondeur_max, compare);
+            mon_tableau = gauche;
         }
     }
+}
 
-    let mon_tableau_gauche: &mut [i32] = mon_tableau_gauche_vec.as_mut_slice();
-    let mon_tableau_droite: &mut [i32] = mon_tableau_droite_vec.as_mut_slice();
-    tri_rapide(mon_tableau_gauche);
-    tri_rapide(mon_tableau_droite);
+// Index de la médiane entre le premier, le dernier et l'élément du milieu
+fn index_mediane_de_trois<T, F>(mon_tableau: &[T], compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (a, b, c) = (0, mon_tableau.len() / 2, mon_tableau.len() - 1);
+    let inferieur = |i: usize, j: usize, compare: &mut F| {
+        compare(&mon_tableau[i], &mon_tableau[j]) == Ordering::Less
+    };
+    if (inferieur(a, b, compare)) {
+        if (inferieur(b, c, compare)) {
+            b
+        } else if (inferieur(a, c, compare)) {
+            c
+        } else {
+            a
+        }
+    } else if (inferieur(a, c, compare)) {
+        a
+    } else if (inferieur(b, c, compare)) {
+        c
+    } else {
+        b
+    }
+}
 
-    // Rassemble les sous-tableaus triés en les copiant dans le tableau d'origine
-    let mut cpt: usize = 0;
-    for elem in mon_tableau_gauche.iter() {
-        mon_tableau[cpt] = *elem;
-        cpt += 1;
+// Partition de Lomuto: le pivot est placé à la fin, puis les éléments plus petits que lui
+// sont rassemblés au début du tableau
+// Renvoie l'index final du pivot
+fn partition_lomuto<T, F>(mon_tableau: &mut [T], index_pivot: usize, compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let dernier = mon_tableau.len() - 1;
+    mon_tableau.swap(index_pivot, dernier);
+
+    // Invariant: les éléments d'index < index_petits sont plus petits que le pivot
+    let mut index_petits = 0;
+    for j in 0..dernier {
+        if (compare(&mon_tableau[j], &mon_tableau[dernier]) == Ordering::Less) {
+            mon_tableau.swap(index_petits, j);
+            index_petits += 1;
+        }
     }
+    mon_tableau.swap(index_petits, dernier);
+    index_petits
+}
 
-    mon_tableau[cpt] = pivot;
-    cpt += 1;
+// Partition de Hoare: le pivot est placé au début, puis deux indices se rapprochent depuis les extrémités,
+// en échangeant les paires d'éléments mal placés (les égaux au pivot arrêtent les deux indices)
+// Renvoie l'index final du pivot
+fn partition_hoare<T, F>(mon_tableau: &mut [T], index_pivot: usize, compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    mon_tableau.swap(0, index_pivot);
+
+    // Invariant: les éléments d'index dans 1..i sont <= pivot, ceux d'index > j sont >= pivot
+    let mut i = 1;
+    let mut j = mon_tableau.len() - 1;
+    loop {
+        while (i <= j && compare(&mon_tableau[i], &mon_tableau[0]) == Ordering::Less) {
+            i += 1;
+        }
+        while (i <= j && compare(&mon_tableau[j], &mon_tableau[0]) == Ordering::Greater) {
+            j -= 1;
+        }
+        if (i >= j) {
+            break;
+        }
+        mon_tableau.swap(i, j);
+        i += 1;
+        j -= 1;
+    }
+    mon_tableau.swap(0, j);
+    j
+}
 
-    for elem in mon_tableau_droite {
-        mon_tableau[cpt] = *elem;
-        cpt += 1;
+// Partition en trois voies (drapeau hollandais de Dijkstra)
+// Renvoie (debut, fin) tels que les éléments d'index dans debut..fin sont égaux au pivot,
+// ceux d'index < debut plus petits, et ceux d'index >= fin plus grands
+fn partition_trois_voies<T, F>(
+    mon_tableau: &mut [T],
+    index_pivot: usize,
+    compare: &mut F,
+) -> (usize, usize)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    mon_tableau.swap(0, index_pivot);
+
+    // Invariant: [0, debut): plus petits, [debut, i): égaux au pivot, [i, fin): à traiter, [fin, n): plus grands
+    // mon_tableau[debut] est donc toujours égal au pivot
+    let mut debut = 0;
+    let mut i = 1;
+    let mut fin = mon_tableau.len();
+    while (i < fin) {
+        match (compare(&mon_tableau[i], &mon_tableau[debut])) {
+            Ordering::Less => {
+                mon_tableau.swap(debut, i);
+                debut += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                fin -= 1;
+                mon_tableau.swap(i, fin);
+            }
+            Ordering::Equal => i += 1,
+        }
     }
+    (debut, fin)
 }
 
 // Algorithme du tri fusion
@@ -289,6 +549,224 @@ where
     );
 } // fn tri_par_tas
 
+// Tri par tas générique et en place, selon une fonction de comparaison 'compare'
+// Contrairement à tri_par_tas_generique, le tas (binaire, racine = plus grand élément)
+// est construit directement dans le tableau: pas d'allocation, ni de clonage
+// Non stable. Complexité: n.log(n), en moyenne et dans le pire cas
+pub fn tri_par_tas_avec_comparaison<T, F>(mon_tableau: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = mon_tableau.len();
+
+    // Construction du tas: on tamise les noeuds internes, des plus profonds jusqu'à la racine
+    for i in (0..(n / 2)).rev() {
+        tamise(mon_tableau, i, &mut compare);
+    }
+
+    for fin in (1..n).rev() {
+        // La racine (plus grand élément du tas) rejoint sa place finale, à la fin de la partie non triée
+        mon_tableau.swap(0, fin);
+        tamise(&mut mon_tableau[..fin], 0, &mut compare);
+
+        // Invariant de boucle: les (n - fin) derniers éléments sont à leur place finale
+    }
+} // fn tri_par_tas_avec_comparaison
+
+// Fait descendre l'élément d'index 'racine' dans le tas, tant qu'un de ses enfants est plus grand
+// (les enfants du noeud i sont les noeuds 2i+1 et 2i+2)
+fn tamise<T, F>(tas: &mut [T], racine: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut parent = racine;
+    loop {
+        let mut enfant = 2 * parent + 1;
+        if (enfant >= tas.len()) {
+            return;
+        }
+        if (enfant + 1 < tas.len() && compare(&tas[enfant], &tas[enfant + 1]) == Ordering::Less) {
+            enfant += 1;
+        }
+        if (compare(&tas[parent], &tas[enfant]) != Ordering::Less) {
+            return;
+        }
+        tas.swap(parent, enfant);
+        parent = enfant;
+    }
+}
+
+// Tri par tas selon une clé extraite de chaque élément
+pub fn tri_par_tas_par_cle<T, K, F>(mon_tableau: &mut [T], mut cle: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    tri_par_tas_avec_comparaison(mon_tableau, |a: &T, b: &T| cle(a).cmp(&cle(b)));
+}
+
+// Paramètres des tris parallèles
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParametresTriParallele {
+    // Nombre maximal de threads travaillant en même temps (1: tri séquentiel)
+    pub nb_threads: usize,
+    // Les sous-tableaux d'au plus 'seuil_sequentiel' éléments sont triés sans créer de thread
+    // (créer un thread coûte plus cher que trier quelques milliers d'éléments)
+    pub seuil_sequentiel: usize,
+}
+
+impl Default for ParametresTriParallele {
+    // Autant de threads que de coeurs disponibles
+    fn default() -> Self {
+        ParametresTriParallele {
+            nb_threads: std::thread::available_parallelism().map_or(1, |nb| nb.get()),
+            seuil_sequentiel: 10000,
+        }
+    }
+}
+
+// Tri rapide parallèle: après chaque partition, les 2 sous-tableaux sont triés en parallèle,
+// chacun avec la moitié des threads restants, jusqu'à n'avoir plus qu'un thread par sous-tableau,
+// qui est alors trié par tri_rapide (séquentiel)
+// Les threads sont 'scoped' (std::thread::scope): ils peuvent emprunter des morceaux du tableau,
+// et sont tous terminés à la fin du scope (pas besoin d'Arc/Mutex comme dans recherche_premiers_multithreading)
+// En place, non stable. Accélération limitée par la première partition, qui reste séquentielle
+pub fn tri_rapide_parallele<T>(mon_tableau: &mut [T], parametres: &ParametresTriParallele)
+where
+    T: Ord + Send,
+{
+    tri_rapide_parallele_avec_comparaison(mon_tableau, parametres, T::cmp);
+}
+
+// Tri rapide parallèle selon une fonction de comparaison 'compare' (partagée entre les threads)
+pub fn tri_rapide_parallele_avec_comparaison<T, F>(
+    mon_tableau: &mut [T],
+    parametres: &ParametresTriParallele,
+    compare: F,
+) where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    tri_rapide_parallele_recursif(
+        mon_tableau,
+        parametres.nb_threads,
+        parametres.seuil_sequentiel,
+        &compare,
+    );
+}
+
+fn tri_rapide_parallele_recursif<T, F>(
+    mon_tableau: &mut [T],
+    nb_threads: usize,
+    seuil_sequentiel: usize,
+    compare: &F,
+) where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    if (nb_threads <= 1 || mon_tableau.len() <= seuil_sequentiel.max(2)) {
+        tri_rapide_avec_comparaison(mon_tableau, compare);
+        return;
+    }
+
+    let mut compare_local = |a: &T, b: &T| compare(a, b);
+    let index_pivot = index_mediane_de_trois(mon_tableau, &mut compare_local);
+    let index = partition_hoare(mon_tableau, index_pivot, &mut compare_local);
+
+    let (gauche, reste) = mon_tableau.split_at_mut(index);
+    let droite = &mut reste[1..];
+    let nb_threads_gauche = nb_threads / 2;
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            tri_rapide_parallele_recursif(gauche, nb_threads_gauche, seuil_sequentiel, compare)
+        });
+        // Le thread courant trie la partie droite
+        tri_rapide_parallele_recursif(
+            droite,
+            nb_threads - nb_threads_gauche,
+            seuil_sequentiel,
+            compare,
+        );
+    });
+}
+
+// Tri fusion parallèle: les 2 moitiés sont triées en parallèle (récursivement, en partageant les threads),
+// puis fusionnées par le thread courant
+// Une seule allocation, comme tri_variantes::tri_fusion_ameliore: un tampon de taille n/2, découpé
+// en même temps que le tableau, chaque thread utilisant sa propre partie
+// Stable, en place. Accélération limitée par la dernière fusion, qui reste séquentielle
+pub fn tri_fusion_parallele<T>(mon_tableau: &mut [T], parametres: &ParametresTriParallele)
+where
+    T: Ord + Clone + Send,
+{
+    tri_fusion_parallele_avec_comparaison(mon_tableau, parametres, T::cmp);
+}
+
+// Tri fusion parallèle selon une fonction de comparaison 'compare' (partagée entre les threads)
+pub fn tri_fusion_parallele_avec_comparaison<T, F>(
+    mon_tableau: &mut [T],
+    parametres: &ParametresTriParallele,
+    compare: F,
+) where
+    T: Clone + Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    // La fusion finale n'a besoin que de la moitié gauche (au plus n/2 éléments)
+    let mut tampon: Vec<T> = mon_tableau[..(mon_tableau.len() / 2)].to_vec();
+    tri_fusion_parallele_recursif(
+        mon_tableau,
+        &mut tampon,
+        parametres.nb_threads,
+        parametres.seuil_sequentiel,
+        &compare,
+    );
+}
+
+fn tri_fusion_parallele_recursif<T, F>(
+    mon_tableau: &mut [T],
+    tampon: &mut [T],
+    nb_threads: usize,
+    seuil_sequentiel: usize,
+    compare: &F,
+) where
+    T: Clone + Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    let mut compare_local = |a: &T, b: &T| compare(a, b);
+    if (nb_threads <= 1 || mon_tableau.len() <= seuil_sequentiel.max(2)) {
+        tri_variantes::tri_fusion_ameliore_recursif(mon_tableau, tampon, &mut compare_local);
+        return;
+    }
+
+    let milieu = mon_tableau.len() / 2;
+    let nb_threads_gauche = nb_threads / 2;
+    {
+        let (gauche, droite) = mon_tableau.split_at_mut(milieu);
+        // Tampon (>= milieu éléments) partagé entre les 2 moitiés: >= milieu/2 pour la gauche,
+        // et le reste (>= (n - milieu)/2) pour la droite
+        let (tampon_gauche, tampon_droite) = tampon.split_at_mut(milieu / 2);
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                tri_fusion_parallele_recursif(
+                    gauche,
+                    tampon_gauche,
+                    nb_threads_gauche,
+                    seuil_sequentiel,
+                    compare,
+                )
+            });
+            tri_fusion_parallele_recursif(
+                droite,
+                tampon_droite,
+                nb_threads - nb_threads_gauche,
+                seuil_sequentiel,
+                compare,
+            );
+        });
+    }
+    tri_variantes::fusionne(mon_tableau, milieu, tampon, &mut compare_local);
+}
+
 // Fonction vérifiant qu'un tableau est croissant
 // Entrée = mon_tableau: tableau d'entiers
 // Sortie = un booleen. true -> tableau croissant..
@@ -0,0 +1,330 @@
+// Ne pas faire de warning s'il y a des parenthèses en trop autour des conditions des if
+#![allow(unused_parens)]
+
+// Tris sans comparaison: les éléments sont répartis selon la valeur de leur clé (ou d'une partie de la clé),
+// ce qui permet de descendre sous la borne n.log(n) des tris par comparaison:
+// - tri par comptage: O(n + k), pour k valeurs de clé possibles (petites étendues de clés)
+// - tri par base (radix), octet par octet: O(n.w) pour des clés de w octets
+//   LSD (octet de poids faible d'abord, stable) ou MSD (poids fort d'abord, récursif)
+// - tri par paquets des flottants: O(n) en moyenne si les valeurs sont bien réparties
+
+use crate::tri_variantes;
+
+// Les sous-tableaux d'au plus SEUIL_INSERTION éléments du tri MSD sont triés par insertion
+const SEUIL_INSERTION: usize = 32;
+
+// Etendue maximale des clés du tri par comptage (un compteur par valeur possible)
+pub const ETENDUE_MAX_COMPTAGE: u64 = 1 << 24;
+
+// Entiers triables par base: la clé est un entier non signé de même ordre que la valeur
+pub trait CleRadix: Copy {
+    // Nombre d'octets de la clé (nombre de passes du tri LSD)
+    const NB_OCTETS: usize;
+    fn cle(self) -> u64;
+}
+
+macro_rules! impl_cle_radix_non_signe {
+    ($($type:ty),*) => {
+        $(impl CleRadix for $type {
+            const NB_OCTETS: usize = std::mem::size_of::<$type>();
+            fn cle(self) -> u64 {
+                self as u64
+            }
+        })*
+    };
+}
+
+// Pour les entiers signés, inverser le bit de signe rend l'ordre des clés non signées
+// identique à celui des valeurs (les négatifs passent avant les positifs)
+macro_rules! impl_cle_radix_signe {
+    ($($type:ty => $type_non_signe:ty),*) => {
+        $(impl CleRadix for $type {
+            const NB_OCTETS: usize = std::mem::size_of::<$type>();
+            fn cle(self) -> u64 {
+                ((self as $type_non_signe) ^ (<$type_non_signe>::MAX / 2 + 1)) as u64
+            }
+        })*
+    };
+}
+
+impl_cle_radix_non_signe!(u8, u16, u32, u64, usize);
+impl_cle_radix_signe!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize);
+
+// Répartit les éléments de 'source' dans 'destination', par ordre croissant de clé (dans 0..nb_cles),
+// en gardant l'ordre d'origine à clé égale (stable)
+// Renvoie false, sans rien copier, si tous les éléments ont la même clé (répartition inutile)
+fn distribue<T, F>(source: &[T], destination: &mut [T], nb_cles: usize, cle: &F) -> bool
+where
+    T: Clone,
+    F: Fn(&T) -> usize,
+{
+    // Nombre d'éléments de chaque clé
+    let mut positions: Vec<usize> = vec![0; nb_cles];
+    for elem in source.iter() {
+        positions[cle(elem)] += 1;
+    }
+    if (positions.contains(&source.len())) {
+        return false;
+    }
+
+    // Sommes cumulées: position du premier élément de chaque clé dans 'destination'
+    let mut total = 0;
+    for position in positions.iter_mut() {
+        let nb = *position;
+        *position = total;
+        total += nb;
+    }
+
+    for elem in source.iter() {
+        let position = &mut positions[cle(elem)];
+        destination[*position] = elem.clone();
+        *position += 1;
+    }
+    true
+}
+
+// Tri par comptage, selon une clé entière comprise dans 0..nb_cles
+// https://fr.wikipedia.org/wiki/Tri_comptage
+// Stable. Complexité: O(n + nb_cles) en temps, O(n + nb_cles) en mémoire
+pub fn tri_comptage_par_cle<T, F>(mon_tableau: &mut [T], nb_cles: usize, cle: F)
+where
+    T: Clone,
+    F: Fn(&T) -> usize,
+{
+    let mut tampon: Vec<T> = mon_tableau.to_vec();
+    if (distribue(mon_tableau, &mut tampon, nb_cles, &cle)) {
+        mon_tableau.clone_from_slice(&tampon);
+    }
+}
+
+// Tri par comptage d'entiers, entre leurs valeurs min et max
+// Réservé aux petites étendues de valeurs: panique si max - min >= ETENDUE_MAX_COMPTAGE
+pub fn tri_comptage<T: CleRadix>(mon_tableau: &mut [T]) {
+    let (cle_min, cle_max) = match (mon_tableau.iter().map(|elem| elem.cle()).min()) {
+        Some(cle_min) => (
+            cle_min,
+            mon_tableau.iter().map(|elem| elem.cle()).max().unwrap(),
+        ),
+        None => return,
+    };
+    assert!(
+        cle_max - cle_min < ETENDUE_MAX_COMPTAGE,
+        "Etendue des valeurs trop grande pour le tri par comptage: utiliser le tri par base."
+    );
+    let nb_cles = (cle_max - cle_min + 1) as usize;
+    tri_comptage_par_cle(mon_tableau, nb_cles, |elem| (elem.cle() - cle_min) as usize);
+}
+
+// Octet numéro 'octet' (0: poids faible) de la clé
+fn octet_cle<T: CleRadix>(elem: &T, octet: usize) -> usize {
+    ((elem.cle() >> (8 * octet)) & 0xFF) as usize
+}
+
+// Tri par base (radix) LSD: un tri par comptage stable par octet, du poids faible au poids fort
+// https://fr.wikipedia.org/wiki/Tri_par_base
+// Stable. Complexité: O(n.w) pour des entiers de w octets, une seule allocation (tampon de taille n)
+pub fn tri_radix_lsd<T: CleRadix>(mon_tableau: &mut [T]) {
+    let mut tampon: Vec<T> = mon_tableau.to_vec();
+    // Les passes alternent entre le tableau et le tampon
+    let mut dans_tampon = false;
+    for octet in 0..T::NB_OCTETS {
+        let cle = |elem: &T| octet_cle(elem, octet);
+        let reparti = if (dans_tampon) {
+            distribue(&tampon, mon_tableau, 256, &cle)
+        } else {
+            distribue(mon_tableau, &mut tampon, 256, &cle)
+        };
+        // (octet identique pour tous les éléments: passe inutile, les données n'ont pas bougé)
+        if (reparti) {
+            dans_tampon = !dans_tampon;
+        }
+    }
+    if (dans_tampon) {
+        mon_tableau.copy_from_slice(&tampon);
+    }
+}
+
+// Tri par base (radix) MSD: répartition selon l'octet de poids fort,
+// puis tri récursif de chaque paquet selon les octets suivants
+// Non stable (tri par insertion sur les petits paquets). Complexité: O(n.w), mais souvent moins:
+// les paquets deviennent vite petits, et les octets de poids faible ne sont pas tous examinés
+pub fn tri_radix_msd<T: CleRadix>(mon_tableau: &mut [T]) {
+    let mut tampon: Vec<T> = mon_tableau.to_vec();
+    tri_radix_msd_recursif(mon_tableau, &mut tampon, T::NB_OCTETS);
+}
+
+fn tri_radix_msd_recursif<T: CleRadix>(mon_tableau: &mut [T], tampon: &mut [T], nb_octets: usize) {
+    if (nb_octets == 0) {
+        return;
+    }
+    if (mon_tableau.len() <= SEUIL_INSERTION) {
+        tri_variantes::tri_par_insertion_generique_avec_comparaison(mon_tableau, |a, b| {
+            a.cle().cmp(&b.cle())
+        });
+        return;
+    }
+
+    let octet = nb_octets - 1;
+    let cle = |elem: &T| octet_cle(elem, octet);
+    if (distribue(mon_tableau, tampon, 256, &cle)) {
+        mon_tableau.copy_from_slice(tampon);
+    }
+
+    // Tri récursif de chaque paquet (éléments consécutifs de même octet)
+    let mut debut = 0;
+    while (debut < mon_tableau.len()) {
+        let valeur_octet = cle(&mon_tableau[debut]);
+        let mut fin = debut + 1;
+        while (fin < mon_tableau.len() && cle(&mon_tableau[fin]) == valeur_octet) {
+            fin += 1;
+        }
+        tri_radix_msd_recursif(
+            &mut mon_tableau[debut..fin],
+            &mut tampon[debut..fin],
+            nb_octets - 1,
+        );
+        debut = fin;
+    }
+}
+
+// Clé de la position 'position' d'une chaine d'octets: 0 après la fin de la chaine (les préfixes passent
+// en premier), et 1 + l'octet sinon
+fn octet_chaine<T: AsRef<[u8]>>(chaine: &T, position: usize) -> usize {
+    match (chaine.as_ref().get(position)) {
+        Some(octet) => *octet as usize + 1,
+        None => 0,
+    }
+}
+
+// Réordonne le tableau selon la permutation (l'élément d'index permutation[k] va en position k),
+// en suivant ses cycles: uniquement des échanges, ni clonage ni allocation de tableau d'éléments
+fn applique_permutation<T>(mon_tableau: &mut [T], permutation: &[usize]) {
+    let mut place: Vec<bool> = vec![false; permutation.len()];
+    for debut in 0..permutation.len() {
+        let mut k = debut;
+        while (!place[k]) {
+            place[k] = true;
+            let source = permutation[k];
+            if (source == debut) {
+                break;
+            }
+            mon_tableau.swap(k, source);
+            k = source;
+        }
+    }
+}
+
+// Tri par base LSD des chaines d'octets (ex: String, Vec<u8>, &[u8]), dans l'ordre lexicographique
+// Une passe par position, de la dernière position de la plus longue chaine à la première
+// Les passes déplacent les index des chaines (pas les chaines), qui sont réordonnées à la fin
+// Stable. Complexité: O(n.L), L étant la longueur de la plus longue chaine
+pub fn tri_radix_lsd_octets<T: AsRef<[u8]>>(mon_tableau: &mut [T]) {
+    let longueur_max: usize = mon_tableau
+        .iter()
+        .map(|chaine| chaine.as_ref().len())
+        .max()
+        .unwrap_or(0);
+    let mut permutation: Vec<usize> = (0..mon_tableau.len()).collect();
+    let mut tampon: Vec<usize> = permutation.clone();
+    for position in (0..longueur_max).rev() {
+        let cle = |index: &usize| octet_chaine(&mon_tableau[*index], position);
+        if (distribue(&permutation, &mut tampon, 257, &cle)) {
+            std::mem::swap(&mut permutation, &mut tampon);
+        }
+    }
+    applique_permutation(mon_tableau, &permutation);
+}
+
+// Tri par base MSD des chaines d'octets, dans l'ordre lexicographique
+// Seuls les préfixes nécessaires pour départager les chaines sont examinés:
+// plus efficace que LSD sur des chaines de longueurs variées
+pub fn tri_radix_msd_octets<T: AsRef<[u8]>>(mon_tableau: &mut [T]) {
+    let mut permutation: Vec<usize> = (0..mon_tableau.len()).collect();
+    let mut tampon: Vec<usize> = permutation.clone();
+    tri_radix_msd_octets_recursif(mon_tableau, &mut permutation, &mut tampon, 0);
+    applique_permutation(mon_tableau, &permutation);
+}
+
+fn tri_radix_msd_octets_recursif<T: AsRef<[u8]>>(
+    mon_tableau: &[T],
+    permutation: &mut [usize],
+    tampon: &mut [usize],
+    position: usize,
+) {
+    if (permutation.len() <= SEUIL_INSERTION) {
+        // (toutes les chaines ont les mêmes 'position' premiers octets)
+        tri_variantes::tri_par_insertion_generique_avec_comparaison(permutation, |i, j| {
+            mon_tableau[*i].as_ref()[position..].cmp(&mon_tableau[*j].as_ref()[position..])
+        });
+        return;
+    }
+
+    let cle = |index: &usize| octet_chaine(&mon_tableau[*index], position);
+    if (distribue(permutation, tampon, 257, &cle)) {
+        permutation.copy_from_slice(tampon);
+    }
+
+    let mut debut = 0;
+    while (debut < permutation.len()) {
+        let valeur_octet = cle(&permutation[debut]);
+        let mut fin = debut + 1;
+        while (fin < permutation.len() && cle(&permutation[fin]) == valeur_octet) {
+            fin += 1;
+        }
+        // Les chaines terminées (clé 0) sont égales: rien à trier
+        if (valeur_octet != 0) {
+            tri_radix_msd_octets_recursif(
+                mon_tableau,
+                &mut permutation[debut..fin],
+                &mut tampon[debut..fin],
+                position + 1,
+            );
+        }
+        debut = fin;
+    }
+}
+
+// Tri par paquets (bucket sort) de flottants
+// https://fr.wikipedia.org/wiki/Tri_par_paquets
+// L'intervalle [min, max] est découpé en n paquets de même largeur, chaque paquet est trié par insertion,
+// puis les paquets sont concaténés
+// Complexité: O(n) en moyenne pour des valeurs uniformément réparties, O(n^2) dans le pire cas
+// (toutes les valeurs dans un même paquet). Pour une loi normale, les paquets centraux sont plus remplis,
+// mais restent petits en moyenne.
+// Les valeurs NaN ne sont pas acceptées (panique)
+pub fn tri_par_paquets(mon_tableau: &mut [f64]) {
+    let n = mon_tableau.len();
+    if (n <= 1) {
+        return;
+    }
+    assert!(
+        mon_tableau.iter().all(|valeur| !valeur.is_nan()),
+        "Le tri par paquets n'accepte pas les valeurs NaN."
+    );
+
+    let min = mon_tableau.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = mon_tableau
+        .iter()
+        .copied()
+        .fold(f64::NEG_INFINITY, f64::max);
+    let largeur = (max - min) / n as f64;
+    if (largeur == 0. || !largeur.is_finite()) {
+        // Toutes les valeurs égales, ou des infinis: pas de découpage possible en paquets
+        tri_variantes::tri_fusion_ameliore_avec_comparaison(mon_tableau, f64::total_cmp);
+        return;
+    }
+
+    let mut paquets: Vec<Vec<f64>> = vec![Vec::new(); n];
+    for valeur in mon_tableau.iter() {
+        // (le max tomberait dans le paquet n: il rejoint le dernier paquet)
+        let index_paquet = (((valeur - min) / largeur) as usize).min(n - 1);
+        paquets[index_paquet].push(*valeur);
+    }
+
+    let mut index = 0;
+    for mut paquet in paquets {
+        tri_variantes::tri_par_insertion_generique_avec_comparaison(&mut paquet, f64::total_cmp);
+        mon_tableau[index..(index + paquet.len())].copy_from_slice(&paquet);
+        index += paquet.len();
+    }
+}
@@ -10,6 +10,8 @@
 // Ne pas faire de warning si des fonctions ne sont pas appelées
 #![warn(dead_code)]
 
+use std::cmp::Ordering;
+
 // Implémentation du tri par insertion, de façon générique (au sens de Rust)
 // Permet de trier n'importe quels tableaux dont le type des éléments implémente les traits:
 // - trait PartialOrd (On dispose de la comparaison d'éléments: x <= y ou y <= x)
@@ -51,6 +53,69 @@ where
     }
 } // fn tri_par_insertion_generique ()
 
+// Tri par insertion générique, selon une fonction de comparaison 'compare'
+// (ex: |a, b| b.cmp(a) pour un tri décroissant)
+// Les éléments sont déplacés par échanges successifs: pas besoin du trait Clone
+// Le tri est stable et en place, sans affichage (utilisé sur les petits sous-tableaux du tri rapide)
+// Complexité: n^2, mais n si le tableau est déjà presque trié
+pub fn tri_par_insertion_generique_avec_comparaison<T, F>(mon_tableau: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for i in 1..mon_tableau.len() {
+        // On fait descendre l'élément d'origine mon_tableau[i], tant que son voisin de gauche est plus grand
+        // En cas d'égalité, on s'arrête => tri stable
+        let mut j = i;
+        while (j > 0 && compare(&mon_tableau[j - 1], &mon_tableau[j]) == Ordering::Greater) {
+            mon_tableau.swap(j - 1, j);
+            j -= 1;
+        }
+
+        // Invariant de boucle:
+        // A la fin de chaque itération, les (i+1) premiers éléments
+        // du tableau 'mon_tableau' sont triés
+    }
+} // fn tri_par_insertion_generique_avec_comparaison ()
+
+// Tri par insertion selon une clé extraite de chaque élément (ex: |personne| personne.age)
+// Stable: les éléments de même clé gardent leur ordre d'origine
+pub fn tri_par_insertion_generique_par_cle<T, K, F>(mon_tableau: &mut [T], mut cle: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    tri_par_insertion_generique_avec_comparaison(mon_tableau, |a: &T, b: &T| cle(a).cmp(&cle(b)));
+}
+
+// Tri par sélection générique et en place, selon une fonction de comparaison 'compare'
+// Non stable (l'échange peut faire passer un élément derrière un autre de même valeur)
+// Complexité: n^2 comparaisons, (n-1) échanges maximum
+pub fn tri_par_selection_avec_comparaison<T, F>(mon_tableau: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = mon_tableau.len();
+    for i in 0..n {
+        // Recherche du plus petit élément, parmi les éléments non triés
+        let mut min_index = i;
+        for j in (i + 1)..n {
+            if (compare(&mon_tableau[j], &mon_tableau[min_index]) == Ordering::Less) {
+                min_index = j;
+            }
+        }
+        mon_tableau.swap(i, min_index);
+    }
+} // fn tri_par_selection_avec_comparaison ()
+
+// Tri par sélection selon une clé extraite de chaque élément
+pub fn tri_par_selection_par_cle<T, K, F>(mon_tableau: &mut [T], mut cle: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    tri_par_selection_avec_comparaison(mon_tableau, |a: &T, b: &T| cle(a).cmp(&cle(b)));
+}
+
 // Fonction qui trouve le minimum pour tout tableau
 // dont les éléments peuvent être comparés
 // Utilisé pour le tri par sélection branchless et indirect en dessous
@@ -113,122 +178,350 @@ where
     mon_tableau_trie
 }
 
+// Tri fusion générique, selon une fonction de comparaison 'compare'
+// Même algorithme que tri::tri_fusion (allocation à chaque fusion), pour tous types clonables
+// Stable: en cas d'égalité, l'élément de la moitié gauche passe en premier
+// Complexité: n.log(n), en moyenne et dans le pire cas
+pub fn tri_fusion_avec_comparaison<T, F>(mon_tableau: &mut [T], mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    tri_fusion_recursif(mon_tableau, &mut compare);
+}
+
+fn tri_fusion_recursif<T, F>(mon_tableau: &mut [T], compare: &mut F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = mon_tableau.len();
+    if (n <= 1) {
+        return;
+    }
+
+    let (gauche, droite) = mon_tableau.split_at_mut(n / 2);
+    tri_fusion_recursif(gauche, compare);
+    tri_fusion_recursif(droite, compare);
+
+    // Fusion des 2 moitiés triées
+    let mut fusion: Vec<T> = Vec::with_capacity(n);
+    let (mut i, mut j) = (0, 0);
+    while (i < gauche.len() && j < droite.len()) {
+        if (compare(&droite[j], &gauche[i]) == Ordering::Less) {
+            fusion.push(droite[j].clone());
+            j += 1;
+        } else {
+            fusion.push(gauche[i].clone());
+            i += 1;
+        }
+    }
+    fusion.extend_from_slice(&gauche[i..]);
+    fusion.extend_from_slice(&droite[j..]);
+    mon_tableau.clone_from_slice(&fusion);
+}
+
+// Tri fusion selon une clé extraite de chaque élément (stable)
+pub fn tri_fusion_par_cle<T, K, F>(mon_tableau: &mut [T], mut cle: F)
+where
+    T: Clone,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    tri_fusion_avec_comparaison(mon_tableau, |a: &T, b: &T| cle(a).cmp(&cle(b)));
+}
+
 // Algorithme du tri fusion - implémentation améliorée (une seule allocation mémoire supplémentaire)
 // Entrée = Sortie:
-// mon_tableau: tableau d'entiers 'mon_tableau'
+// mon_tableau: tableau d'éléments ordonnés (trait Ord) et clonables
 // Implémentation tri stable et en place
 // Complexité: n.log(n), en moyenne et dans le pire cas
-// Version optimisée: Une seule allocation interne au premier appel à la fonction, de taille moitié du tableau d'origine
+// Version optimisée: Une seule allocation, au début du tri, d'un tampon de taille moitié du tableau d'origine
 // Voir: https://fr.wikipedia.org/wiki/Tri_fusion
-#[allow(dead_code)]
-pub fn tri_fusion_ameliore(
-    mon_tableau: &mut [i32],
-    index_min_opt: Option<usize>,
-    index_max_opt: Option<usize>,
-    mon_sous_tableau_1_opt: Option<&mut [i32]>,
-) {
-    let arguments_manquants: bool = (index_min_opt.is_none())
-        || (index_max_opt.is_none())
-        || ((mon_sous_tableau_1_opt.as_ref()).is_none());
-    if arguments_manquants {
-        println!("Appel à tri_fusion_ameliore");
-        // On traite le tout premier appel à la fonction, effectué par l'utilisateur (i.e. non récursif)
-        let len_tableau = mon_tableau.len();
-        let len_sous_tableau_1: usize = len_tableau / 2 + 1;
-        // Unique allocation de cet algorithme - Effectué une seule fois lors de l'appel utilisateur
-        let mut mon_sous_tableau_1_vec: Vec<i32> = vec![0; len_sous_tableau_1];
-        let mon_sous_tableau_1: &mut [i32] = mon_sous_tableau_1_vec.as_mut_slice();
-        // Relance la fonction, avec le tableau alloué, et les bons index cette fois
-        return tri_fusion_ameliore(
-            mon_tableau,
-            Some(0),
-            Some(len_tableau - 1),
-            Some(mon_sous_tableau_1),
-        );
-    }
+pub fn tri_fusion_ameliore<T>(mon_tableau: &mut [T])
+where
+    T: Ord + Clone,
+{
+    tri_fusion_ameliore_avec_comparaison(mon_tableau, T::cmp);
+}
 
-    // A partir d'ici, on sait que les arguments optionnels sont effectivement fournis (pas None)
-    // index_min_opt, index_max_opt et mon_sous_tableau_1_opt
-    // On peut unwrap() sans risque
-    let index_min: usize = index_min_opt.unwrap();
-    let index_max: usize = index_max_opt.unwrap();
-    let mon_sous_tableau_1: &mut [i32] = mon_sous_tableau_1_opt.unwrap();
+// Tri fusion amélioré, selon une fonction de comparaison 'compare'
+pub fn tri_fusion_ameliore_avec_comparaison<T, F>(mon_tableau: &mut [T], mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // Unique allocation de cet algorithme: la moitié gauche a au plus n/2 éléments
+    // (le contenu initial du tampon n'a pas d'importance, il est écrasé à chaque fusion)
+    let mut tampon: Vec<T> = mon_tableau[..(mon_tableau.len() / 2)].to_vec();
+    tri_fusion_ameliore_recursif(mon_tableau, &mut tampon, &mut compare);
+}
 
-    let n = index_max - index_min + 1;
+pub(crate) fn tri_fusion_ameliore_recursif<T, F>(
+    mon_tableau: &mut [T],
+    tampon: &mut [T],
+    compare: &mut F,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = mon_tableau.len();
 
     // Gestion des cas particuliers (fin des appels récursifs)
     // tableau avec 1 seul élément (-> Plus rien à trier)
-    if n <= 1 {
+    if (n <= 1) {
         return;
     }
 
-    // Gestion des cas particuliers (fin des appels récursifs)
-    // tableau avec 2 élements
-    if n == 2 {
-        // Permuter les elements d'indice 0 et 1 si nécessaire
-        if mon_tableau[index_min] > mon_tableau[index_max] {
-            mon_tableau.swap(index_max, index_min);
-        }
+    // Cas général, menant à 2 appels récursifs, suivis de la fusion des 2 sous-tableaux
+    let milieu = n / 2;
+    tri_fusion_ameliore_recursif(&mut mon_tableau[..milieu], tampon, compare);
+    tri_fusion_ameliore_recursif(&mut mon_tableau[milieu..], tampon, compare);
+    fusionne(mon_tableau, milieu, tampon, compare);
+}
+
+// Fusion des sous-tableaux triés mon_tableau[..milieu] et mon_tableau[milieu..]
+// Le premier sous-tableau est copié dans le tampon (de taille >= milieu),
+// puis la fusion est effectuée directement dans le tableau principal
+pub(crate) fn fusionne<T, F>(
+    mon_tableau: &mut [T],
+    milieu: usize,
+    tampon: &mut [T],
+    compare: &mut F,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // Sous-tableaux déjà dans l'ordre (ex: tableau presque trié): rien à fusionner
+    if (compare(&mon_tableau[milieu - 1], &mon_tableau[milieu]) != Ordering::Greater) {
         return;
     }
 
-    // Cas général, menant à 2 appels récursifs, suivis de la fusion des 2 sous-tableaus
-    let mid = (index_min + index_max) / 2;
-    let mid_plus_1 = mid + 1;
+    let tampon = &mut tampon[..milieu];
+    tampon.clone_from_slice(&mon_tableau[..milieu]);
 
-    // Appels récursifs pour trier chacun des 2 sous-tableaus
-    tri_fusion_ameliore(
-        mon_tableau,
-        Some(index_min),
-        Some(mid),
-        Some(mon_sous_tableau_1),
-    );
-    tri_fusion_ameliore(
-        mon_tableau,
-        Some(mid_plus_1),
-        Some(index_max),
-        Some(mon_sous_tableau_1),
-    );
+    let mut index_input_1 = 0;
+    let mut index_input_2 = milieu;
+    let mut index_output = 0;
 
-    // Fusion des 2 sous-tableaus
-    // On copie le premier sous-tableau dans un tableau à part
-    // puis on effectue la fusion dans le tableau principal
-    let len_sous_tableau_1 = mid - index_min + 1;
+    // Invariant de boucle: index_output = index_input_1 + (index_input_2 - milieu) <= index_input_2
+    // L'écriture en index_output n'écrase donc jamais un élément du second sous-tableau non encore fusionné
+    while (index_input_1 < milieu && index_input_2 < mon_tableau.len()) {
+        if (compare(&mon_tableau[index_input_2], &tampon[index_input_1]) == Ordering::Less) {
+            // On copie un élément du tableau 2
+            mon_tableau[index_output] = mon_tableau[index_input_2].clone();
+            index_input_2 += 1;
+        } else {
+            // En cas d'égalité, on prend en priorité un élement du premier tableau
+            // => Tri stable
+            mon_tableau[index_output] = tampon[index_input_1].clone();
+            index_input_1 += 1;
+        }
+        index_output += 1;
+    }
 
-    mon_sous_tableau_1[..len_sous_tableau_1].copy_from_slice(&mon_tableau[index_min..(mid + 1)]);
+    // Tableau 2 épuisé: on recopie la fin du tableau 1
+    // (si c'est le tableau 1 qui est épuisé, la fin du tableau 2 est déjà à sa place)
+    let reste_1 = milieu - index_input_1;
+    mon_tableau[index_output..(index_output + reste_1)].clone_from_slice(&tampon[index_input_1..]);
+} // fn fusionne
 
-    let mut fusion_index_input_1 = 0;
-    let mut fusion_index_input_2 = mid_plus_1;
+// Tri fusion ascendant (non récursif): on fusionne les paires de sous-tableaux de taille 1,
+// puis de taille 2, 4, 8.. jusqu'à ce que le tableau entier soit trié
+// Stable, en place, une seule allocation (tampon de la taille du plus grand sous-tableau gauche fusionné)
+// Complexité: n.log(n), en moyenne et dans le pire cas
+pub fn tri_fusion_ascendant<T>(mon_tableau: &mut [T])
+where
+    T: Ord + Clone,
+{
+    tri_fusion_ascendant_avec_comparaison(mon_tableau, T::cmp);
+}
 
-    for fusion_index_output in index_min..(index_max + 1) {
-        let tableau_1_non_epuise = (fusion_index_input_1 <= (mid - index_min));
-        let tableau_2_non_epuise = (fusion_index_input_2 <= index_max);
+// Tri fusion ascendant, selon une fonction de comparaison 'compare'
+pub fn tri_fusion_ascendant_avec_comparaison<T, F>(mon_tableau: &mut [T], mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = mon_tableau.len();
+    if (n <= 1) {
+        return;
+    }
 
-        if (tableau_1_non_epuise) {
-            if (tableau_2_non_epuise) {
-                if (mon_tableau[fusion_index_input_2] < mon_sous_tableau_1[fusion_index_input_1]) {
-                    // On copie un élément du tableau 2
-                    mon_tableau[fusion_index_output] = mon_tableau[fusion_index_input_2];
-                    fusion_index_input_2 += 1;
-                } else {
-                    // En cas d'égalité, on prend en priorité un élement du premier tableau
-                    // => Tri stable
+    // Le sous-tableau gauche de la dernière fusion a pour taille la plus grande puissance de 2 < n
+    let taille_tampon = n.next_power_of_two() / 2;
+    let mut tampon: Vec<T> = mon_tableau[..taille_tampon].to_vec();
+
+    let mut largeur = 1;
+    while (largeur < n) {
+        // Fusion des paires de sous-tableaux [debut, debut + largeur) et [debut + largeur, debut + 2.largeur)
+        let mut debut = 0;
+        while (debut + largeur < n) {
+            let fin = n.min(debut + 2 * largeur);
+            fusionne(
+                &mut mon_tableau[debut..fin],
+                largeur,
+                &mut tampon,
+                &mut compare,
+            );
+            debut = fin;
+        }
+        // Invariant de boucle: les sous-tableaux de taille 2.largeur (alignés) sont triés
+        largeur *= 2;
+    }
+} // fn tri_fusion_ascendant_avec_comparaison
+
+// TimSort (de Tim Peters): tri fusion naturel, qui exploite les séquences déjà triées du tableau
+// https://en.wikipedia.org/wiki/Timsort
+// - le tableau est découpé en séquences croissantes (les séquences strictement décroissantes sont inversées),
+//   prolongées par insertion jusqu'à une longueur minimale 'min_run' (entre 32 et 64)
+// - les séquences sont empilées, et fusionnées de façon à garder des longueurs équilibrées
+// Stable, en place, une seule allocation (tampon de taille n)
+// Complexité: n.log(n) dans le pire cas, et n si le tableau est déjà trié (ou trié à l'envers)
+pub fn tri_timsort<T>(mon_tableau: &mut [T])
+where
+    T: Ord + Clone,
+{
+    tri_timsort_avec_comparaison(mon_tableau, T::cmp);
+}
+
+// TimSort selon une fonction de comparaison 'compare'
+pub fn tri_timsort_avec_comparaison<T, F>(mon_tableau: &mut [T], mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = mon_tableau.len();
+    if (n <= 1) {
+        return;
+    }
+    let min_run = calcule_min_run(n);
+    let mut tampon: Vec<T> = mon_tableau.to_vec();
+
+    // Pile des séquences triées, en attente de fusion: (début, longueur)
+    let mut sequences: Vec<(usize, usize)> = Vec::new();
+    let mut debut = 0;
+    while (debut < n) {
+        let mut longueur = longueur_sequence(&mut mon_tableau[debut..], &mut compare);
+        if (longueur < min_run) {
+            // Séquence trop courte: prolongée par insertion (rapide, son début étant déjà trié)
+            longueur = min_run.min(n - debut);
+            tri_par_insertion_generique_avec_comparaison(
+                &mut mon_tableau[debut..(debut + longueur)],
+                &mut compare,
+            );
+        }
+        sequences.push((debut, longueur));
+        debut += longueur;
 
-                    // On copie un élément du tableau 1
-                    mon_tableau[fusion_index_output] = mon_sous_tableau_1[fusion_index_input_1];
-                    fusion_index_input_1 += 1;
+        // Invariants de la pile (les longueurs décroissent au moins comme la suite de Fibonacci):
+        // pour 3 séquences consécutives X, Y, Z (Z au sommet): X > Y + Z et Y > Z
+        loop {
+            let k = sequences.len();
+            let longueur_k = |i: usize| sequences[k - i].1;
+            if ((k >= 3 && longueur_k(3) <= longueur_k(2) + longueur_k(1))
+                || (k >= 4 && longueur_k(4) <= longueur_k(3) + longueur_k(2)))
+            {
+                // On fusionne Y avec la plus petite de ses voisines
+                if (longueur_k(3) < longueur_k(1)) {
+                    fusionne_sequences(
+                        mon_tableau,
+                        &mut sequences,
+                        k - 3,
+                        &mut tampon,
+                        &mut compare,
+                    );
+                } else {
+                    fusionne_sequences(
+                        mon_tableau,
+                        &mut sequences,
+                        k - 2,
+                        &mut tampon,
+                        &mut compare,
+                    );
                 }
+            } else if (k >= 2 && longueur_k(2) <= longueur_k(1)) {
+                fusionne_sequences(
+                    mon_tableau,
+                    &mut sequences,
+                    k - 2,
+                    &mut tampon,
+                    &mut compare,
+                );
             } else {
-                // tableau 2 épuisé
-                // On copie un élément du tableau 1
-                mon_tableau[fusion_index_output] = mon_sous_tableau_1[fusion_index_input_1];
-                fusion_index_input_1 += 1;
+                break;
             }
-        } else if (tableau_2_non_epuise) {
-            // tableau 1 épuisé
-            // On copie un élément du tableau 2
-            mon_tableau[fusion_index_output] = mon_tableau[fusion_index_input_2];
-            fusion_index_input_2 += 1;
         }
     }
-} // fn tri_fusion_ameliore
+
+    // Fusion des séquences restantes, depuis le sommet de la pile
+    while (sequences.len() > 1) {
+        let k = sequences.len();
+        fusionne_sequences(
+            mon_tableau,
+            &mut sequences,
+            k - 2,
+            &mut tampon,
+            &mut compare,
+        );
+    }
+} // fn tri_timsort_avec_comparaison
+
+// Longueur minimale des séquences: n / min_run est une puissance de 2 ou un peu moins,
+// pour que les fusions finales soient équilibrées
+fn calcule_min_run(n: usize) -> usize {
+    let mut n = n;
+    let mut bits_restants = 0;
+    while (n >= 64) {
+        bits_restants |= n & 1;
+        n >>= 1;
+    }
+    n + bits_restants
+}
+
+// Longueur de la séquence triée au début du tableau
+// Une séquence strictement décroissante est inversée (strictement: pour que le tri reste stable)
+fn longueur_sequence<T, F>(mon_tableau: &mut [T], compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = mon_tableau.len();
+    if (n <= 1) {
+        return n;
+    }
+    let mut fin = 2;
+    if (compare(&mon_tableau[1], &mon_tableau[0]) == Ordering::Less) {
+        while (fin < n && compare(&mon_tableau[fin], &mon_tableau[fin - 1]) == Ordering::Less) {
+            fin += 1;
+        }
+        mon_tableau[..fin].reverse();
+    } else {
+        while (fin < n && compare(&mon_tableau[fin], &mon_tableau[fin - 1]) != Ordering::Less) {
+            fin += 1;
+        }
+    }
+    fin
+}
+
+// Fusionne les séquences consécutives d'index i et i + 1 de la pile
+fn fusionne_sequences<T, F>(
+    mon_tableau: &mut [T],
+    sequences: &mut Vec<(usize, usize)>,
+    i: usize,
+    tampon: &mut [T],
+    compare: &mut F,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (debut, longueur_1) = sequences[i];
+    let longueur_2 = sequences[i + 1].1;
+    fusionne(
+        &mut mon_tableau[debut..(debut + longueur_1 + longueur_2)],
+        longueur_1,
+        tampon,
+        compare,
+    );
+    sequences[i] = (debut, longueur_1 + longueur_2);
+    sequences.remove(i + 1);
+}